@@ -0,0 +1,134 @@
+//! Compares the memory locality of [`MortonGrid`] and of the row-major [`CartesianGrid`] on 3d access patterns which visit elements close in space.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ghx_grid::{
+    cartesian::{
+        coordinates::{Cartesian3D, CartesianPosition},
+        grid::CartesianGrid,
+        morton::MortonGrid,
+    },
+    coordinate_system::CoordinateSystem,
+    grid::{Grid, GridData},
+};
+
+const SIZES: [u32; 2] = [32, 128];
+const BLOCK_SIZE: u32 = 8;
+
+/// Sums, for each element, the values of its neighbours. Neighbours on the Y and Z axes are far in memory in row-major order.
+fn sum_neighbours<G: Grid<Cartesian3D>>(data: &GridData<Cartesian3D, u32, G>) -> u64 {
+    let grid = data.grid();
+    let mut sum = 0;
+    for index in 0..grid.total_size() {
+        for direction in grid.coord_system().directions() {
+            if let Some(neighbour) = grid.neighbour(index as _, *direction) {
+                sum += u64::from(*data.get(neighbour));
+            }
+        }
+    }
+    sum
+}
+
+/// Visits the grid block by block, as done when processing chunks of a voxel world.
+fn sum_blocks<G: Grid<Cartesian3D, Position = CartesianPosition>>(
+    data: &GridData<Cartesian3D, u32, G>,
+    size: u32,
+) -> u64 {
+    let grid = data.grid();
+    let mut sum = 0;
+    for block_z in (0..size).step_by(BLOCK_SIZE as usize) {
+        for block_y in (0..size).step_by(BLOCK_SIZE as usize) {
+            for block_x in (0..size).step_by(BLOCK_SIZE as usize) {
+                for z in block_z..block_z + BLOCK_SIZE {
+                    for y in block_y..block_y + BLOCK_SIZE {
+                        for x in block_x..block_x + BLOCK_SIZE {
+                            let pos = CartesianPosition::new(x, y, z);
+                            sum += u64::from(*data.get(grid.index_from_pos(&pos)));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    sum
+}
+
+fn bench_neighbours(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum_neighbours");
+    for size in SIZES {
+        let row_major = CartesianGrid::new_cartesian_3d(size, size, size, false, false, false)
+            .new_grid_data(1u32);
+        let morton =
+            MortonGrid::new(size, size, size, false, false, false, Cartesian3D).new_grid_data(1u32);
+        group.bench_with_input(
+            BenchmarkId::new("row_major", size),
+            &row_major,
+            |b, data| b.iter(|| sum_neighbours(black_box(data))),
+        );
+        group.bench_with_input(BenchmarkId::new("morton", size), &morton, |b, data| {
+            b.iter(|| sum_neighbours(black_box(data)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_blocks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum_blocks");
+    for size in SIZES {
+        let row_major = CartesianGrid::new_cartesian_3d(size, size, size, false, false, false)
+            .new_grid_data(1u32);
+        let morton =
+            MortonGrid::new(size, size, size, false, false, false, Cartesian3D).new_grid_data(1u32);
+        group.bench_with_input(
+            BenchmarkId::new("row_major", size),
+            &row_major,
+            |b, data| b.iter(|| sum_blocks(black_box(data), size)),
+        );
+        group.bench_with_input(BenchmarkId::new("morton", size), &morton, |b, data| {
+            b.iter(|| sum_blocks(black_box(data), size))
+        });
+    }
+    group.finish();
+}
+
+/// Element of 64 bytes, a cache line, so that memory accesses weigh more than index computations
+#[derive(Clone, Copy)]
+struct Voxel([u64; 8]);
+
+fn bench_large_elements(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum_neighbours_64_bytes");
+    let size = 128;
+    let row_major = CartesianGrid::new_cartesian_3d(size, size, size, false, false, false)
+        .new_grid_data(Voxel([1; 8]));
+    let morton = MortonGrid::new(size, size, size, false, false, false, Cartesian3D)
+        .new_grid_data(Voxel([1; 8]));
+    group.bench_with_input(
+        BenchmarkId::new("row_major", size),
+        &row_major,
+        |b, data| b.iter(|| sum_large_neighbours(black_box(data))),
+    );
+    group.bench_with_input(BenchmarkId::new("morton", size), &morton, |b, data| {
+        b.iter(|| sum_large_neighbours(black_box(data)))
+    });
+    group.finish();
+}
+
+fn sum_large_neighbours<G: Grid<Cartesian3D>>(data: &GridData<Cartesian3D, Voxel, G>) -> u64 {
+    let grid = data.grid();
+    let mut sum = 0;
+    for index in 0..grid.total_size() {
+        for direction in grid.coord_system().directions() {
+            if let Some(neighbour) = grid.neighbour(index as _, *direction) {
+                sum += data.get(neighbour).0[0];
+            }
+        }
+    }
+    sum
+}
+
+criterion_group!(
+    benches,
+    bench_neighbours,
+    bench_blocks,
+    bench_large_elements
+);
+criterion_main!(benches);
@@ -0,0 +1,128 @@
+//! Compares the ways of decoding the positions of a [`CartesianGrid`]: [`CartesianGrid::pos_from_index`], [`CartesianGrid::position_walker`] and [`CartesianGrid::positions_cache`].
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ghx_grid::{
+    cartesian::{
+        coordinates::{Cartesian3D, CartesianPosition},
+        grid::CartesianGrid,
+    },
+    grid::{Grid, GridIndex},
+};
+
+const SIZES: [(u32, u32, u32); 2] = [(64, 64, 64), (256, 256, 16)];
+
+fn grid(size: (u32, u32, u32)) -> CartesianGrid<Cartesian3D> {
+    CartesianGrid::new_cartesian_3d(size.0, size.1, size.2, false, false, false)
+}
+
+fn size_id(size: (u32, u32, u32)) -> String {
+    format!("{}x{}x{}", size.0, size.1, size.2)
+}
+
+#[inline]
+fn sum_coords(pos: CartesianPosition) -> u64 {
+    u64::from(pos.x) + u64::from(pos.y) + u64::from(pos.z)
+}
+
+/// Indexes of all the elements of the grid in a scattered order, as when decoding the positions of arbitrary elements
+fn scattered_indexes(total_size: usize) -> Vec<GridIndex> {
+    // 7919 is prime and does not divide the benchmarked sizes, so this visits each index once
+    (0..total_size)
+        .map(|i| ((i * 7919) % total_size) as GridIndex)
+        .collect()
+}
+
+fn bench_full_pass(c: &mut Criterion) {
+    let mut group = c.benchmark_group("positions_full_pass");
+    for size in SIZES {
+        let grid = grid(size);
+        let cache = grid.positions_cache();
+        group.bench_with_input(
+            BenchmarkId::new("pos_from_index", size_id(size)),
+            &grid,
+            |b, grid| {
+                b.iter(|| {
+                    (0..grid.total_size())
+                        .map(|index| sum_coords(grid.pos_from_index(black_box(index as GridIndex))))
+                        .sum::<u64>()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("position_walker", size_id(size)),
+            &grid,
+            |b, grid| {
+                b.iter(|| {
+                    black_box(grid)
+                        .position_walker()
+                        .map(sum_coords)
+                        .sum::<u64>()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("positions_cache", size_id(size)),
+            &cache,
+            |b, cache| {
+                b.iter(|| {
+                    black_box(cache)
+                        .iter()
+                        .copied()
+                        .map(sum_coords)
+                        .sum::<u64>()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("positions_cache_with_build", size_id(size)),
+            &grid,
+            |b, grid| {
+                b.iter(|| {
+                    black_box(grid)
+                        .positions_cache()
+                        .into_iter()
+                        .map(sum_coords)
+                        .sum::<u64>()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_scattered(c: &mut Criterion) {
+    let mut group = c.benchmark_group("positions_scattered");
+    for size in SIZES {
+        let grid = grid(size);
+        let cache = grid.positions_cache();
+        let indexes = scattered_indexes(grid.total_size());
+        group.bench_with_input(
+            BenchmarkId::new("pos_from_index", size_id(size)),
+            &indexes,
+            |b, indexes| {
+                b.iter(|| {
+                    indexes
+                        .iter()
+                        .map(|index| sum_coords(grid.pos_from_index(black_box(*index))))
+                        .sum::<u64>()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("positions_cache", size_id(size)),
+            &indexes,
+            |b, indexes| {
+                b.iter(|| {
+                    indexes
+                        .iter()
+                        .map(|index| sum_coords(cache[black_box(*index) as usize]))
+                        .sum::<u64>()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_full_pass, bench_scattered);
+criterion_main!(benches);
@@ -0,0 +1,234 @@
+use crate::{
+    coordinate_system::CoordinateSystem,
+    direction::{Axis, Direction},
+    error::GridError,
+    grid::{Grid, GridData, GridIndex, NodeRef},
+};
+use alloc::{vec, vec::Vec};
+
+use super::{
+    coordinates::{CartesianCoordinates, CartesianPosition},
+    grid::CartesianGrid,
+};
+
+#[cfg(feature = "bevy")]
+use bevy::ecs::component::Component;
+#[cfg(feature = "reflect")]
+use bevy::{ecs::reflect::ReflectComponent, reflect::Reflect};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Order in which the axes vary along the data buffer of an [`AxisSwappedGrid`], from the fastest varying one to the slowest.
+///
+/// [`AxisOrder::XYZ`] is the order of a [`CartesianGrid`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AxisOrder {
+    /// X varies fastest, then Y, then Z
+    #[default]
+    XYZ,
+    /// X varies fastest, then Z, then Y
+    XZY,
+    /// Y varies fastest, then X, then Z
+    YXZ,
+    /// Y varies fastest, then Z, then X
+    YZX,
+    /// Z varies fastest, then X, then Y
+    ZXY,
+    /// Z varies fastest, then Y, then X
+    ZYX,
+}
+
+impl AxisOrder {
+    /// Returns the axes, from the fastest varying one to the slowest
+    pub fn axes(&self) -> [Axis; 3] {
+        match self {
+            AxisOrder::XYZ => [Axis::X, Axis::Y, Axis::Z],
+            AxisOrder::XZY => [Axis::X, Axis::Z, Axis::Y],
+            AxisOrder::YXZ => [Axis::Y, Axis::X, Axis::Z],
+            AxisOrder::YZX => [Axis::Y, Axis::Z, Axis::X],
+            AxisOrder::ZXY => [Axis::Z, Axis::X, Axis::Y],
+            AxisOrder::ZYX => [Axis::Z, Axis::Y, Axis::X],
+        }
+    }
+}
+
+/// Definition of a cartesian grid whose elements are indexed with a permutation of the axes, see [`AxisOrder`].
+///
+/// Positions are the same as in a [`CartesianGrid`] of the same size, only the mapping between positions and indexes differs: a [`GridData`] on this grid iterates in the permuted order, which acts as a lazy transpose.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Component, Default))]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AxisSwappedGrid<C: CoordinateSystem> {
+    grid: CartesianGrid<C>,
+    order: AxisOrder,
+    /// Index offset of a unit step on each axis, indexed by [`Axis`]
+    strides: [u64; 3],
+}
+
+impl<C: CartesianCoordinates> AxisSwappedGrid<C> {
+    /// Creates a new [`AxisSwappedGrid`] with the sizes and looping flags of `grid`, indexed in `order`
+    pub fn new(grid: CartesianGrid<C>, order: AxisOrder) -> AxisSwappedGrid<C> {
+        let mut strides = [0; 3];
+        let mut stride = 1;
+        for axis in order.axes() {
+            strides[axis as usize] = stride;
+            stride *= grid.axis_size(axis) as u64;
+        }
+        Self {
+            grid,
+            order,
+            strides,
+        }
+    }
+
+    /// Returns the [`CartesianGrid`] with the same sizes and looping flags as this grid, which can be used to reason about positions.
+    #[inline]
+    pub fn cartesian_grid(&self) -> &CartesianGrid<C> {
+        &self.grid
+    }
+
+    /// Returns the order of the axes of this grid
+    #[inline]
+    pub fn order(&self) -> AxisOrder {
+        self.order
+    }
+
+    /// Returns the index from a grid position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn index_from_coords(&self, x: u32, y: u32, z: u32) -> GridIndex {
+        (x as u64 * self.strides[0] + y as u64 * self.strides[1] + z as u64 * self.strides[2])
+            as GridIndex
+    }
+
+    /// Returns the index from a grid position.
+    ///
+    /// NO CHECK is done to verify that the given `grid_position` is a valid position for this grid.
+    #[inline]
+    pub fn index_from_pos(&self, grid_position: &CartesianPosition) -> GridIndex {
+        self.index_from_coords(grid_position.x, grid_position.y, grid_position.z)
+    }
+
+    /// Returns a [`CartesianPosition`] from the index of an element in this [`AxisSwappedGrid`].
+    ///
+    /// NO CHECK is done to verify that the given index is a valid index for this grid.
+    #[inline]
+    pub fn pos_from_index(&self, grid_index: GridIndex) -> CartesianPosition {
+        let mut remainder = grid_index as u64;
+        let mut coords = [0; 3];
+        for axis in self.order.axes() {
+            let size = self.grid.axis_size(axis) as u64;
+            coords[axis as usize] = (remainder % size) as u32;
+            remainder /= size;
+        }
+        CartesianPosition::new(coords[0], coords[1], coords[2])
+    }
+
+    /// Creates a default [`GridData`] with the size of the [`AxisSwappedGrid`] with each element value set to its default one.
+    pub fn default_grid_data<D: Default + Clone>(&self) -> GridData<C, D, AxisSwappedGrid<C>> {
+        GridData::new(self.clone(), vec![D::default(); self.total_size()])
+    }
+
+    /// Creates a [`GridData`] with the size of the [`AxisSwappedGrid`] with each element value being a copy of the given one.
+    pub fn new_grid_data<D: Clone>(&self, element: D) -> GridData<C, D, AxisSwappedGrid<C>> {
+        GridData::new(self.clone(), vec![element; self.total_size()])
+    }
+}
+
+impl<C: CartesianCoordinates> CartesianGrid<C> {
+    /// Returns an [`AxisSwappedGrid`] with the sizes and looping flags of this grid, indexed in `order`
+    pub fn with_axes(self, order: AxisOrder) -> AxisSwappedGrid<C> {
+        AxisSwappedGrid::new(self, order)
+    }
+}
+
+impl<C: CartesianCoordinates> Grid<C> for AxisSwappedGrid<C> {
+    type Position = C::Position;
+
+    #[inline]
+    fn coord_system(&self) -> &C {
+        self.grid.coord_system()
+    }
+
+    #[inline]
+    fn directions_count(&self) -> usize {
+        self.grid.directions_count()
+    }
+
+    #[inline]
+    fn total_size(&self) -> usize {
+        self.grid.total_size()
+    }
+
+    fn get_neighbours_in_all_directions(
+        &self,
+        grid_index: GridIndex,
+        neighbours_buffer: &mut Vec<Option<GridIndex>>,
+    ) {
+        let pos = AxisSwappedGrid::pos_from_index(self, grid_index);
+        for dir in self.grid.coord_system().directions() {
+            neighbours_buffer[usize::from(*dir)] = self
+                .grid
+                .get_next_pos_in_direction(&pos, *dir)
+                .map(|next_pos| AxisSwappedGrid::index_from_pos(self, &next_pos));
+        }
+    }
+
+    #[inline]
+    fn neighbour(&self, index: GridIndex, direction: Direction) -> Option<GridIndex> {
+        self.grid
+            .get_next_pos_in_direction(&AxisSwappedGrid::pos_from_index(self, index), direction)
+            .map(|next_pos| AxisSwappedGrid::index_from_pos(self, &next_pos))
+    }
+
+    #[inline]
+    fn index_from_pos(&self, grid_position: &C::Position) -> GridIndex {
+        AxisSwappedGrid::index_from_pos(self, &(*grid_position).into())
+    }
+
+    #[inline]
+    fn pos_from_index(&self, grid_index: GridIndex) -> C::Position {
+        C::position_from_cartesian(AxisSwappedGrid::pos_from_index(self, grid_index))
+    }
+
+    #[inline]
+    fn is_valid_pos(&self, grid_position: &C::Position) -> bool {
+        self.grid.is_valid_pos(grid_position)
+    }
+}
+
+impl<C: CartesianCoordinates> NodeRef<C, AxisSwappedGrid<C>> for CartesianPosition {
+    #[inline]
+    fn to_index(&self, grid: &AxisSwappedGrid<C>) -> GridIndex {
+        grid.index_from_pos(self)
+    }
+
+    #[inline]
+    fn try_to_index(&self, grid: &AxisSwappedGrid<C>) -> Result<GridIndex, GridError> {
+        grid.cartesian_grid().check_coords(self.x, self.y, self.z)?;
+        Ok(grid.index_from_pos(self))
+    }
+}
+
+impl<C: CartesianCoordinates, D> GridData<C, D, AxisSwappedGrid<C>> {
+    /// Returns a reference to the element at this position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn get_from_pos(&self, pos: &CartesianPosition) -> &D {
+        self.get(self.grid().index_from_pos(pos))
+    }
+
+    /// Returns a mutable reference to the element at this position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn get_mut_from_pos(&mut self, pos: &CartesianPosition) -> &mut D {
+        self.get_mut(self.grid().index_from_pos(pos))
+    }
+}
@@ -1,6 +1,9 @@
-use std::fmt;
+use core::{cmp::Ordering, fmt};
 
-use crate::{coordinate_system::CoordinateSystem, direction::Direction};
+use crate::{
+    coordinate_system::CoordinateSystem,
+    direction::{Axis, Direction},
+};
 
 #[cfg(feature = "bevy")]
 use bevy::ecs::component::Component;
@@ -11,7 +14,7 @@ use bevy::{ecs::reflect::ReflectComponent, reflect::Reflect};
 use serde::{Deserialize, Serialize};
 
 /// Right-handed 2d Cartesian coordinate system: 4 directions
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy", derive(Component))]
 #[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -28,8 +31,20 @@ impl CoordinateSystem for Cartesian2D {
     fn directions_count(&self) -> usize {
         CARTESIAN_2D_DIRECTIONS.len()
     }
+
+    #[inline]
+    fn direction_deltas(&self) -> Option<&[GridDelta]> {
+        Some(self.deltas())
+    }
 }
 impl CartesianCoordinates for Cartesian2D {
+    type Position = CartesianPosition2D;
+
+    #[inline]
+    fn position_from_cartesian(pos: CartesianPosition) -> CartesianPosition2D {
+        CartesianPosition2D::new(pos.x, pos.y)
+    }
+
     #[inline]
     fn deltas(&self) -> &'static [GridDelta] {
         CARTESIAN_2D_DELTAS
@@ -37,7 +52,7 @@ impl CartesianCoordinates for Cartesian2D {
 }
 
 /// Right-handed 3d Cartesian coordinate system: 6 directions
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy", derive(Component))]
 #[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -54,8 +69,20 @@ impl CoordinateSystem for Cartesian3D {
     fn directions_count(&self) -> usize {
         CARTESIAN_3D_DIRECTIONS.len()
     }
+
+    #[inline]
+    fn direction_deltas(&self) -> Option<&[GridDelta]> {
+        Some(self.deltas())
+    }
 }
 impl CartesianCoordinates for Cartesian3D {
+    type Position = CartesianPosition;
+
+    #[inline]
+    fn position_from_cartesian(pos: CartesianPosition) -> CartesianPosition {
+        pos
+    }
+
     #[inline]
     fn deltas(&self) -> &'static [GridDelta] {
         CARTESIAN_3D_DELTAS
@@ -63,7 +90,7 @@ impl CartesianCoordinates for Cartesian3D {
 }
 
 /// All the directions that forms a 2d cartesian coordinate system
-pub const CARTESIAN_2D_DIRECTIONS: &'static [Direction] = &[
+pub const CARTESIAN_2D_DIRECTIONS: &[Direction] = &[
     Direction::XForward,
     Direction::YForward,
     Direction::XBackward,
@@ -71,7 +98,7 @@ pub const CARTESIAN_2D_DIRECTIONS: &'static [Direction] = &[
 ];
 
 /// All the [`GridDelta`], one for each direction, in a cartesian 2d coordinate system
-pub const CARTESIAN_2D_DELTAS: &'static [GridDelta] = &[
+pub const CARTESIAN_2D_DELTAS: &[GridDelta] = &[
     GridDelta {
         // XForward
         dx: 1,
@@ -99,7 +126,7 @@ pub const CARTESIAN_2D_DELTAS: &'static [GridDelta] = &[
 ];
 
 /// All the directions that forms a 3d cartesian coordinate system
-pub const CARTESIAN_3D_DIRECTIONS: &'static [Direction] = &[
+pub const CARTESIAN_3D_DIRECTIONS: &[Direction] = &[
     Direction::XForward,
     Direction::YForward,
     Direction::XBackward,
@@ -109,7 +136,7 @@ pub const CARTESIAN_3D_DIRECTIONS: &'static [Direction] = &[
 ];
 
 /// All the [`GridDelta`], one for each direction, in a cartesian 3d coordinate system
-pub const CARTESIAN_3D_DELTAS: &'static [GridDelta] = &[
+pub const CARTESIAN_3D_DELTAS: &[GridDelta] = &[
     GridDelta {
         // XForward
         dx: 1,
@@ -148,8 +175,94 @@ pub const CARTESIAN_3D_DELTAS: &'static [GridDelta] = &[
     },
 ];
 
+/// Neighbourhood of an element in a cartesian grid, independent from the directions of the coordinate system
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Neighbourhood {
+    /// Elements sharing a face: 4 neighbours in 2d, 6 in 3d
+    #[default]
+    VonNeumann,
+    /// Elements sharing a face, an edge or a corner: 8 neighbours in 2d, 26 in 3d
+    Moore,
+}
+
+impl Neighbourhood {
+    /// Returns the deltas leading to the neighbours, in 2d or 3d
+    pub fn deltas(&self, three_dimensional: bool) -> &'static [GridDelta] {
+        match (self, three_dimensional) {
+            (Neighbourhood::VonNeumann, false) => CARTESIAN_2D_DELTAS,
+            (Neighbourhood::VonNeumann, true) => CARTESIAN_3D_DELTAS,
+            (Neighbourhood::Moore, false) => MOORE_2D_DELTAS,
+            (Neighbourhood::Moore, true) => MOORE_3D_DELTAS,
+        }
+    }
+}
+
+/// Metric used to measure the distance between two positions of a cartesian grid
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DistanceMetric {
+    /// Sum of the distances on each axis
+    Manhattan,
+    /// Maximum of the distances on each axis
+    #[default]
+    Chebyshev,
+    /// Straight-line distance
+    Euclidean,
+}
+
+impl DistanceMetric {
+    /// Returns `true` if a position at the distances `(dx, dy, dz)` on each axis is within `radius`
+    pub fn is_within(&self, (dx, dy, dz): (u32, u32, u32), radius: u32) -> bool {
+        match self {
+            DistanceMetric::Manhattan => {
+                u64::from(dx) + u64::from(dy) + u64::from(dz) <= u64::from(radius)
+            }
+            DistanceMetric::Chebyshev => dx.max(dy).max(dz) <= radius,
+            DistanceMetric::Euclidean => {
+                let square = |d: u32| u64::from(d) * u64::from(d);
+                square(dx) + square(dy) + square(dz) <= square(radius)
+            }
+        }
+    }
+}
+
+/// Deltas to the 8 neighbours of the 2d Moore neighbourhood
+pub const MOORE_2D_DELTAS: &[GridDelta] = &moore_deltas::<8>(0);
+/// Deltas to the 26 neighbours of the 3d Moore neighbourhood
+pub const MOORE_3D_DELTAS: &[GridDelta] = &moore_deltas::<26>(1);
+
+/// Returns all the non-null deltas with components in `-1..=1` (`-z_extent..=z_extent` on the Z axis), by increasing z, then y, then x
+const fn moore_deltas<const N: usize>(z_extent: i32) -> [GridDelta; N] {
+    let mut deltas = [GridDelta {
+        dx: 0,
+        dy: 0,
+        dz: 0,
+    }; N];
+    let mut i = 0;
+    let mut dz = -z_extent;
+    while dz <= z_extent {
+        let mut dy = -1;
+        while dy <= 1 {
+            let mut dx = -1;
+            while dx <= 1 {
+                if dx != 0 || dy != 0 || dz != 0 {
+                    deltas[i] = GridDelta { dx, dy, dz };
+                    i += 1;
+                }
+                dx += 1;
+            }
+            dy += 1;
+        }
+        dz += 1;
+    }
+    deltas
+}
+
 /// Represents a displacement on a grid
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy", derive(Component))]
 #[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -167,9 +280,72 @@ impl GridDelta {
     pub fn new(dx: i32, dy: i32, dz: i32) -> Self {
         Self { dx, dy, dz }
     }
+
+    /// Returns the Manhattan length of this delta: the sum of the absolute values of its components, computed as a `u64` so that it can not overflow
+    pub fn manhattan_len(&self) -> u64 {
+        u64::from(self.dx.unsigned_abs())
+            + u64::from(self.dy.unsigned_abs())
+            + u64::from(self.dz.unsigned_abs())
+    }
+
+    /// Returns `true` if exactly one component is 1 or -1 and the others are 0
+    pub fn is_unit(&self) -> bool {
+        self.manhattan_len() == 1
+    }
+
+    /// Returns a [`GridDelta`] with each component replaced by its sign: -1, 0 or 1
+    pub fn signum(&self) -> GridDelta {
+        GridDelta {
+            dx: self.dx.signum(),
+            dy: self.dy.signum(),
+            dz: self.dz.signum(),
+        }
+    }
+
+    /// Returns this delta multiplied by `rhs`, or `None` if any component overflows
+    pub fn checked_mul(self, rhs: i32) -> Option<GridDelta> {
+        Some(GridDelta {
+            dx: self.dx.checked_mul(rhs)?,
+            dy: self.dy.checked_mul(rhs)?,
+            dz: self.dz.checked_mul(rhs)?,
+        })
+    }
+
+    /// Returns this delta multiplied by `rhs`, with each component saturating at the numeric bounds instead of overflowing
+    pub fn saturating_mul(self, rhs: i32) -> GridDelta {
+        GridDelta {
+            dx: self.dx.saturating_mul(rhs),
+            dy: self.dy.saturating_mul(rhs),
+            dz: self.dz.saturating_mul(rhs),
+        }
+    }
+
+    /// Returns this delta rotated by a quarter turn counter-clockwise around `axis`, when looking from the positive side of the axis (right-handed rotation).
+    ///
+    /// Around Z, `(dx, dy, dz)` becomes `(-dy, dx, dz)`.
+    pub fn rotated_ccw_about(&self, axis: Axis) -> GridDelta {
+        let GridDelta { dx, dy, dz } = *self;
+        match axis {
+            Axis::X => GridDelta::new(dx, -dz, dy),
+            Axis::Y => GridDelta::new(dz, dy, -dx),
+            Axis::Z => GridDelta::new(-dy, dx, dz),
+        }
+    }
+
+    /// Returns this delta rotated by a quarter turn clockwise around `axis`, when looking from the positive side of the axis. Inverse of [`GridDelta::rotated_ccw_about`].
+    ///
+    /// Around Z, `(dx, dy, dz)` becomes `(dy, -dx, dz)`.
+    pub fn rotated_cw_about(&self, axis: Axis) -> GridDelta {
+        let GridDelta { dx, dy, dz } = *self;
+        match axis {
+            Axis::X => GridDelta::new(dx, dz, -dy),
+            Axis::Y => GridDelta::new(-dz, dy, dx),
+            Axis::Z => GridDelta::new(dy, -dx, dz),
+        }
+    }
 }
 
-impl std::ops::Mul<i32> for GridDelta {
+impl core::ops::Mul<i32> for GridDelta {
     type Output = GridDelta;
     fn mul(self, rhs: i32) -> GridDelta {
         GridDelta {
@@ -180,10 +356,66 @@ impl std::ops::Mul<i32> for GridDelta {
     }
 }
 
+impl core::ops::Add for GridDelta {
+    type Output = GridDelta;
+    fn add(self, rhs: GridDelta) -> GridDelta {
+        GridDelta {
+            dx: self.dx + rhs.dx,
+            dy: self.dy + rhs.dy,
+            dz: self.dz + rhs.dz,
+        }
+    }
+}
+
+impl core::ops::AddAssign for GridDelta {
+    fn add_assign(&mut self, rhs: GridDelta) {
+        *self = *self + rhs;
+    }
+}
+
+impl core::ops::Sub for GridDelta {
+    type Output = GridDelta;
+    fn sub(self, rhs: GridDelta) -> GridDelta {
+        GridDelta {
+            dx: self.dx - rhs.dx,
+            dy: self.dy - rhs.dy,
+            dz: self.dz - rhs.dz,
+        }
+    }
+}
+
+impl core::ops::Neg for GridDelta {
+    type Output = GridDelta;
+    fn neg(self) -> GridDelta {
+        self * -1
+    }
+}
+
+/// Sums the deltas component-wise, starting from a null delta.
+impl core::iter::Sum for GridDelta {
+    fn sum<I: Iterator<Item = GridDelta>>(iter: I) -> GridDelta {
+        iter.fold(GridDelta::new(0, 0, 0), |acc, delta| acc + delta)
+    }
+}
+
 /// Specific case for a cartesian coordinate system
 pub trait CartesianCoordinates: CoordinateSystem<Direction = Direction> {
+    /// Position type used by grids in this coordinate system
+    type Position: fmt::Debug + Copy + Into<CartesianPosition>;
+
+    /// Converts a [`CartesianPosition`] into a position of this coordinate system, dropping the unused axes
+    fn position_from_cartesian(pos: CartesianPosition) -> Self::Position;
+
     /// Returns the [`GridDelta`] for each direction in this coordinate system
-    fn deltas(&self) -> &'static [GridDelta];
+    fn deltas(&self) -> &[GridDelta];
+
+    /// Returns an iterator over the directions of this coordinate system paired with their [`GridDelta`], in the order of [`CoordinateSystem::directions`]
+    fn directions_and_deltas(&self) -> impl Iterator<Item = (Direction, GridDelta)> + '_ {
+        self.directions()
+            .iter()
+            .copied()
+            .zip(self.deltas().iter().copied())
+    }
 }
 
 /// Represents a position in a grid in a practical format
@@ -217,9 +449,153 @@ impl CartesianPosition {
     pub fn new_xy(x: u32, y: u32) -> Self {
         Self { x, y, z: 0 }
     }
+
+    /// Returns this position moved by `delta`, or `None` if any of the resulting coordinates would be out of the `u32` range.
+    pub fn checked_add(&self, delta: GridDelta) -> Option<CartesianPosition> {
+        let (x, y, z) = self.get_delta_position(&delta);
+        Some(CartesianPosition {
+            x: u32::try_from(x).ok()?,
+            y: u32::try_from(y).ok()?,
+            z: u32::try_from(z).ok()?,
+        })
+    }
+
+    /// Returns the [`GridDelta`] to move from `rhs` to this position, or `None` if any component of the delta would be out of the `i32` range.
+    pub fn checked_sub(&self, rhs: &CartesianPosition) -> Option<GridDelta> {
+        Some(GridDelta {
+            dx: i32::try_from(i64::from(self.x) - i64::from(rhs.x)).ok()?,
+            dy: i32::try_from(i64::from(self.y) - i64::from(rhs.y)).ok()?,
+            dz: i32::try_from(i64::from(self.z) - i64::from(rhs.z)).ok()?,
+        })
+    }
+
+    /// Returns the coordinates of this position as an `[x, y, z]` array
+    pub fn to_array(&self) -> [u32; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Returns the x and y coordinates of this position as an `[x, y]` array
+    pub fn to_array_2d(&self) -> [u32; 2] {
+        [self.x, self.y]
+    }
+
+    /// Creates a position from an `[x, y, z]` array
+    pub fn from_array([x, y, z]: [u32; 3]) -> Self {
+        Self { x, y, z }
+    }
+}
+/// Saturating addition: each coordinate of the result is clamped to the `u32` range.
+///
+/// See [`CartesianPosition::checked_add`] for a checked version.
+impl core::ops::Add<GridDelta> for CartesianPosition {
+    type Output = CartesianPosition;
+    fn add(self, rhs: GridDelta) -> CartesianPosition {
+        let saturate = |coord: i64| coord.clamp(0, i64::from(u32::MAX)) as u32;
+        let (x, y, z) = self.get_delta_position(&rhs);
+        CartesianPosition {
+            x: saturate(x),
+            y: saturate(y),
+            z: saturate(z),
+        }
+    }
+}
+/// Saturating addition, see the [`core::ops::Add`] implementation.
+impl core::ops::AddAssign<GridDelta> for CartesianPosition {
+    fn add_assign(&mut self, rhs: GridDelta) {
+        *self = *self + rhs;
+    }
 }
+/// Returns the [`GridDelta`] to move from `rhs` to `self`.
+///
+/// Panics if a component of the delta does not fit in an `i32`, see [`CartesianPosition::checked_sub`].
+impl core::ops::Sub<CartesianPosition> for CartesianPosition {
+    type Output = GridDelta;
+    fn sub(self, rhs: CartesianPosition) -> GridDelta {
+        self.checked_sub(&rhs)
+            .expect("the delta between the positions does not fit in a GridDelta")
+    }
+}
+impl From<(u32, u32, u32)> for CartesianPosition {
+    fn from((x, y, z): (u32, u32, u32)) -> Self {
+        Self { x, y, z }
+    }
+}
+impl From<(u32, u32)> for CartesianPosition {
+    fn from((x, y): (u32, u32)) -> Self {
+        Self::new_xy(x, y)
+    }
+}
+impl From<[u32; 3]> for CartesianPosition {
+    fn from(coords: [u32; 3]) -> Self {
+        Self::from_array(coords)
+    }
+}
+impl From<[u32; 2]> for CartesianPosition {
+    fn from([x, y]: [u32; 2]) -> Self {
+        Self::new_xy(x, y)
+    }
+}
+impl From<CartesianPosition> for [u32; 3] {
+    fn from(pos: CartesianPosition) -> Self {
+        pos.to_array()
+    }
+}
+/// Positions are ordered by z, then y, then x: the order in which [`super::grid::CartesianGrid::indexes`] visits them.
+impl Ord for CartesianPosition {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.z, self.y, self.x).cmp(&(other.z, other.y, other.x))
+    }
+}
+
+impl PartialOrd for CartesianPosition {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl fmt::Display for CartesianPosition {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "x: {}, y: {}, z: {}", self.x, self.y, self.z)
     }
 }
+
+/// Represents a position in a 2d grid in a practical format
+#[derive(Default, Hash, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CartesianPosition2D {
+    /// Position on the x axis
+    pub x: u32,
+    /// Position on the y axis
+    pub y: u32,
+}
+impl CartesianPosition2D {
+    /// Utility constructor
+    pub fn new(x: u32, y: u32) -> Self {
+        Self { x, y }
+    }
+}
+impl From<CartesianPosition2D> for CartesianPosition {
+    fn from(pos: CartesianPosition2D) -> Self {
+        CartesianPosition::new_xy(pos.x, pos.y)
+    }
+}
+/// Positions are ordered by y, then x: the order in which [`super::grid::CartesianGrid::indexes`] visits them.
+impl Ord for CartesianPosition2D {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for CartesianPosition2D {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for CartesianPosition2D {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "x: {}, y: {}", self.x, self.y)
+    }
+}
@@ -0,0 +1,312 @@
+use crate::{
+    coordinate_system::CoordinateSystem,
+    direction::{Axis, DirectionIndex, DirectionTrait},
+    error::GridError,
+    grid::{Grid, GridData, GridIndex, NodeRef},
+};
+use alloc::{vec, vec::Vec};
+
+use super::{
+    coordinates::{Cartesian3D, CartesianPosition, GridDelta},
+    grid::CartesianGrid,
+};
+
+#[cfg(feature = "bevy")]
+use bevy::ecs::component::Component;
+#[cfg(feature = "reflect")]
+use bevy::{ecs::reflect::ReflectComponent, reflect::Reflect};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Represents a direction in a [`CustomCoordinates`] coordinate system: one of its deltas.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CustomDirection {
+    index: DirectionIndex,
+    opposite: DirectionIndex,
+    axis: Axis,
+}
+
+impl From<CustomDirection> for DirectionIndex {
+    fn from(direction: CustomDirection) -> Self {
+        direction.index
+    }
+}
+
+impl DirectionTrait for CustomDirection {
+    /// Returns the direction of the opposite delta, or this direction if the coordinate system has no opposite delta
+    fn opposite(&self) -> CustomDirection {
+        CustomDirection {
+            index: self.opposite,
+            opposite: self.index,
+            axis: self.axis,
+        }
+    }
+
+    /// Custom directions have no rotation basis: always returns an empty slice
+    fn rotation_basis(&self) -> &'static [CustomDirection] {
+        &[]
+    }
+
+    /// Returns the axis of the largest component of the delta of this direction
+    fn axis(&self) -> Axis {
+        self.axis
+    }
+}
+
+/// Coordinate system whose directions are defined at runtime by a list of [`GridDelta`], to use with a [`CustomGrid`].
+///
+/// The direction at index `i` moves by the delta at index `i`.
+///
+/// Deserialized coordinate systems are validated: their directions must be the ones [`CustomCoordinates::new`] creates from their deltas.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "CustomCoordinatesFields"))]
+pub struct CustomCoordinates {
+    deltas: Vec<GridDelta>,
+    directions: Vec<CustomDirection>,
+}
+
+/// Serialized fields of a [`CustomCoordinates`], validated when converted to a coordinate system
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "CustomCoordinates")]
+struct CustomCoordinatesFields {
+    deltas: Vec<GridDelta>,
+    directions: Vec<CustomDirection>,
+}
+
+/// Returns [`GridError::LengthMismatch`] if there is not one direction per delta, and [`GridError::InvalidDirectionIndex`] with the index of the first direction which does not match its delta.
+#[cfg(feature = "serde")]
+impl TryFrom<CustomCoordinatesFields> for CustomCoordinates {
+    type Error = GridError;
+
+    fn try_from(fields: CustomCoordinatesFields) -> Result<Self, Self::Error> {
+        if fields.directions.len() != fields.deltas.len() {
+            return Err(GridError::LengthMismatch {
+                expected: fields.deltas.len(),
+                got: fields.directions.len(),
+            });
+        }
+        let coord_system = CustomCoordinates::new(fields.deltas);
+        match coord_system
+            .directions
+            .iter()
+            .zip(fields.directions.iter())
+            .position(|(expected, got)| expected != got)
+        {
+            Some(index) => Err(GridError::InvalidDirectionIndex { index }),
+            None => Ok(coord_system),
+        }
+    }
+}
+
+impl CustomCoordinates {
+    /// Creates a new [`CustomCoordinates`] with one direction for each of the given `deltas`
+    pub fn new(deltas: Vec<GridDelta>) -> CustomCoordinates {
+        let directions = deltas
+            .iter()
+            .enumerate()
+            .map(|(index, delta)| CustomDirection {
+                index,
+                opposite: deltas
+                    .iter()
+                    .position(|other| *other == *delta * -1)
+                    .unwrap_or(index),
+                axis: main_axis(delta),
+            })
+            .collect();
+        Self { deltas, directions }
+    }
+
+    /// Returns the [`GridDelta`] for each direction in this coordinate system
+    #[inline]
+    pub fn deltas(&self) -> &[GridDelta] {
+        &self.deltas
+    }
+
+    /// Returns the [`GridDelta`] of `direction`
+    #[inline]
+    pub fn delta(&self, direction: CustomDirection) -> &GridDelta {
+        &self.deltas[direction.index]
+    }
+}
+
+impl CoordinateSystem for CustomCoordinates {
+    type Direction = CustomDirection;
+
+    #[inline]
+    fn directions(&self) -> &[CustomDirection] {
+        &self.directions
+    }
+
+    #[inline]
+    fn directions_count(&self) -> usize {
+        self.directions.len()
+    }
+
+    #[inline]
+    fn direction_deltas(&self) -> Option<&[GridDelta]> {
+        Some(self.deltas())
+    }
+}
+
+/// Returns the axis of the largest component of `delta`, favoring X then Y on ties
+fn main_axis(delta: &GridDelta) -> Axis {
+    let (x, y, z) = (
+        delta.dx.unsigned_abs(),
+        delta.dy.unsigned_abs(),
+        delta.dz.unsigned_abs(),
+    );
+    if x >= y && x >= z {
+        Axis::X
+    } else if y >= z {
+        Axis::Y
+    } else {
+        Axis::Z
+    }
+}
+
+/// Definition of a cartesian grid using a [`CustomCoordinates`] coordinate system: the neighbours of an element are the positions reached by each of its deltas.
+///
+/// Positions and indexes are the same as in a 3d [`CartesianGrid`] of the same size, and neighbours respect its looping flags.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Component, Default))]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CustomGrid {
+    grid: CartesianGrid<Cartesian3D>,
+    coord_system: CustomCoordinates,
+}
+
+impl CustomGrid {
+    /// Creates a new [`CustomGrid`] with the sizes and looping flags of `grid` and the directions of `coord_system`
+    pub fn new(grid: CartesianGrid<Cartesian3D>, coord_system: CustomCoordinates) -> CustomGrid {
+        Self { grid, coord_system }
+    }
+
+    /// Returns the [`CartesianGrid`] with the same sizes and looping flags as this grid, which can be used to reason about positions.
+    #[inline]
+    pub fn cartesian_grid(&self) -> &CartesianGrid<Cartesian3D> {
+        &self.grid
+    }
+
+    /// Returns the position of the neighbour of `grid_position` in `direction`.
+    ///
+    /// Returns `None` if the destination is not in the grid.
+    ///
+    /// NO CHECK is done to verify that the given `grid_position` is a valid position for this grid.
+    #[inline]
+    pub fn get_next_pos_in_direction(
+        &self,
+        grid_position: &CartesianPosition,
+        direction: CustomDirection,
+    ) -> Option<CartesianPosition> {
+        self.grid
+            .get_next_pos(grid_position, self.coord_system.delta(direction))
+    }
+
+    /// Creates a default [`GridData`] with the size of the [`CustomGrid`] with each element value set to its default one.
+    pub fn default_grid_data<D: Default + Clone>(
+        &self,
+    ) -> GridData<CustomCoordinates, D, CustomGrid> {
+        GridData::new(self.clone(), vec![D::default(); self.total_size()])
+    }
+
+    /// Creates a [`GridData`] with the size of the [`CustomGrid`] with each element value being a copy of the given one.
+    pub fn new_grid_data<D: Clone>(
+        &self,
+        element: D,
+    ) -> GridData<CustomCoordinates, D, CustomGrid> {
+        GridData::new(self.clone(), vec![element; self.total_size()])
+    }
+}
+
+impl Grid<CustomCoordinates> for CustomGrid {
+    type Position = CartesianPosition;
+
+    #[inline]
+    fn coord_system(&self) -> &CustomCoordinates {
+        &self.coord_system
+    }
+
+    #[inline]
+    fn directions_count(&self) -> usize {
+        self.coord_system.directions_count()
+    }
+
+    #[inline]
+    fn total_size(&self) -> usize {
+        self.grid.total_size()
+    }
+
+    fn get_neighbours_in_all_directions(
+        &self,
+        grid_index: GridIndex,
+        neighbours_buffer: &mut Vec<Option<GridIndex>>,
+    ) {
+        let pos = self.grid.pos_from_index(grid_index);
+        for (neighbour, delta) in neighbours_buffer.iter_mut().zip(self.coord_system.deltas()) {
+            *neighbour = self
+                .grid
+                .get_next_pos(&pos, delta)
+                .map(|next_pos| self.grid.index_from_pos(&next_pos));
+        }
+    }
+
+    #[inline]
+    fn neighbour(&self, index: GridIndex, direction: CustomDirection) -> Option<GridIndex> {
+        self.get_next_pos_in_direction(&self.grid.pos_from_index(index), direction)
+            .map(|next_pos| self.grid.index_from_pos(&next_pos))
+    }
+
+    #[inline]
+    fn index_from_pos(&self, grid_position: &CartesianPosition) -> GridIndex {
+        self.grid.index_from_pos(grid_position)
+    }
+
+    #[inline]
+    fn pos_from_index(&self, grid_index: GridIndex) -> CartesianPosition {
+        self.grid.pos_from_index(grid_index)
+    }
+
+    #[inline]
+    fn is_valid_pos(&self, grid_position: &CartesianPosition) -> bool {
+        self.grid.is_valid_pos(grid_position)
+    }
+}
+
+impl NodeRef<CustomCoordinates, CustomGrid> for CartesianPosition {
+    #[inline]
+    fn to_index(&self, grid: &CustomGrid) -> GridIndex {
+        grid.index_from_pos(self)
+    }
+
+    #[inline]
+    fn try_to_index(&self, grid: &CustomGrid) -> Result<GridIndex, GridError> {
+        grid.cartesian_grid().check_coords(self.x, self.y, self.z)?;
+        Ok(grid.index_from_pos(self))
+    }
+}
+
+impl<D> GridData<CustomCoordinates, D, CustomGrid> {
+    /// Returns a reference to the element at this position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn get_from_pos(&self, pos: &CartesianPosition) -> &D {
+        self.get(self.grid().index_from_pos(pos))
+    }
+
+    /// Returns a mutable reference to the element at this position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn get_mut_from_pos(&mut self, pos: &CartesianPosition) -> &mut D {
+        self.get_mut(self.grid().index_from_pos(pos))
+    }
+}
@@ -0,0 +1,157 @@
+use core::slice::{Iter, IterMut};
+
+use crate::{
+    error::GridError,
+    grid::{Grid, GridIndex},
+};
+
+use super::{
+    coordinates::{Cartesian2D, Cartesian3D, CartesianCoordinates, CartesianPosition},
+    grid::CartesianGrid,
+};
+
+/// Holds a [`CartesianGrid`] and its data inline in an array of `N` elements, without any heap allocation.
+///
+/// `N` is always equal to the `total_size` of the grid.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FixedGridData<C: CartesianCoordinates, D, const N: usize> {
+    grid: CartesianGrid<C>,
+    data: [D; N],
+}
+
+impl<C: CartesianCoordinates, D, const N: usize> FixedGridData<C, D, N> {
+    /// Creates a new [`FixedGridData`] from a grid and its data.
+    ///
+    /// Returns [`GridError::LengthMismatch`] if `N` is not equal to the `total_size` of the grid.
+    pub fn new(grid: CartesianGrid<C>, data: [D; N]) -> Result<Self, GridError> {
+        if grid.total_size() != N {
+            return Err(GridError::LengthMismatch {
+                expected: grid.total_size(),
+                got: N,
+            });
+        }
+        Ok(Self { grid, data })
+    }
+
+    /// Creates a new [`FixedGridData`] with each element value being a copy of the given one.
+    ///
+    /// Returns [`GridError::LengthMismatch`] if `N` is not equal to the `total_size` of the grid.
+    pub fn filled(grid: CartesianGrid<C>, element: D) -> Result<Self, GridError>
+    where
+        D: Copy,
+    {
+        Self::new(grid, [element; N])
+    }
+
+    /// Returns a reference to the grid definition.
+    #[inline]
+    pub fn grid(&self) -> &CartesianGrid<C> {
+        &self.grid
+    }
+
+    /// Returns a reference to the element at this index.
+    ///
+    /// NO CHECK is done to verify that the given index is a valid index for this grid.
+    #[inline]
+    pub fn get(&self, index: GridIndex) -> &D {
+        &self.data[index as usize]
+    }
+
+    /// Returns a mutable reference to the element at this index.
+    ///
+    /// NO CHECK is done to verify that the given index is a valid index for this grid.
+    #[inline]
+    pub fn get_mut(&mut self, index: GridIndex) -> &mut D {
+        &mut self.data[index as usize]
+    }
+
+    /// Sets the value of the element at `index` in the grid.
+    ///
+    /// NO CHECK is done to verify that the given index is a valid index for this grid.
+    #[inline]
+    pub fn set(&mut self, index: GridIndex, value: D) {
+        self.data[index as usize] = value;
+    }
+
+    /// Returns a reference to the element at this position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn get_from_pos(&self, pos: &CartesianPosition) -> &D {
+        self.get(self.grid.index_from_pos(pos))
+    }
+
+    /// Returns a mutable reference to the element at this position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn get_mut_from_pos(&mut self, pos: &CartesianPosition) -> &mut D {
+        self.get_mut(self.grid.index_from_pos(pos))
+    }
+
+    /// Returns the data buffer as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[D] {
+        &self.data
+    }
+
+    /// Returns the data buffer as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [D] {
+        &mut self.data
+    }
+
+    /// Returns an iterator over all the elements, in index order.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, D> {
+        self.data.iter()
+    }
+
+    /// Returns a mutable iterator over all the elements, in index order.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, D> {
+        self.data.iter_mut()
+    }
+
+    /// Decomposes this `FixedGridData` into its grid definition and its data array.
+    #[inline]
+    pub fn into_raw(self) -> (CartesianGrid<C>, [D; N]) {
+        (self.grid, self.data)
+    }
+}
+
+impl<D, const N: usize> FixedGridData<Cartesian2D, D, N> {
+    /// Returns a reference to the data at this position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn get_2d(&self, x: u32, y: u32) -> &D {
+        self.get(self.grid.get_index_2d(x, y))
+    }
+
+    /// Returns a mutable reference to the data at this position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn get_2d_mut(&mut self, x: u32, y: u32) -> &mut D {
+        self.get_mut(self.grid.get_index_2d(x, y))
+    }
+}
+
+impl<D, const N: usize> FixedGridData<Cartesian3D, D, N> {
+    /// Returns a reference to the data at this position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn get_3d(&self, x: u32, y: u32, z: u32) -> &D {
+        self.get(self.grid.index_from_coords(x, y, z))
+    }
+
+    /// Returns a mutable reference to the data at this position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn get_3d_mut(&mut self, x: u32, y: u32, z: u32) -> &mut D {
+        self.get_mut(self.grid.index_from_coords(x, y, z))
+    }
+}
@@ -0,0 +1,49 @@
+use core::num::TryFromIntError;
+
+use ::glam::{IVec3, UVec3};
+
+use super::coordinates::{CartesianPosition, GridDelta};
+
+impl From<CartesianPosition> for UVec3 {
+    fn from(pos: CartesianPosition) -> Self {
+        UVec3::new(pos.x, pos.y, pos.z)
+    }
+}
+
+/// Coordinates greater than `i32::MAX` wrap around.
+impl From<CartesianPosition> for IVec3 {
+    fn from(pos: CartesianPosition) -> Self {
+        IVec3::new(pos.x as i32, pos.y as i32, pos.z as i32)
+    }
+}
+
+impl From<UVec3> for CartesianPosition {
+    fn from(vec: UVec3) -> Self {
+        CartesianPosition::new(vec.x, vec.y, vec.z)
+    }
+}
+
+/// Fails if any component is negative.
+impl TryFrom<IVec3> for CartesianPosition {
+    type Error = TryFromIntError;
+
+    fn try_from(vec: IVec3) -> Result<Self, Self::Error> {
+        Ok(CartesianPosition::new(
+            u32::try_from(vec.x)?,
+            u32::try_from(vec.y)?,
+            u32::try_from(vec.z)?,
+        ))
+    }
+}
+
+impl From<IVec3> for GridDelta {
+    fn from(vec: IVec3) -> Self {
+        GridDelta::new(vec.x, vec.y, vec.z)
+    }
+}
+
+impl From<GridDelta> for IVec3 {
+    fn from(delta: GridDelta) -> Self {
+        IVec3::new(delta.dx, delta.dy, delta.dz)
+    }
+}
@@ -1,13 +1,16 @@
-use std::{fmt, ops::Range};
+use alloc::{vec, vec::Vec};
+use core::{fmt, ops::Range};
 
 use crate::{
     coordinate_system::CoordinateSystem,
-    direction::Direction,
-    grid::{Grid, GridData, GridIndex, NodeRef},
+    direction::{Axis, Direction, DirectionTrait},
+    error::GridError,
+    grid::{grid_data_ref::GridDataRef, Grid, GridData, GridIndex, NodeRef},
 };
 
 use super::coordinates::{
-    Cartesian2D, Cartesian3D, CartesianCoordinates, CartesianPosition, GridDelta,
+    Cartesian2D, Cartesian3D, CartesianCoordinates, CartesianPosition, CartesianPosition2D,
+    DistanceMetric, GridDelta, Neighbourhood,
 };
 
 #[cfg(feature = "bevy")]
@@ -19,10 +22,13 @@ use bevy::{ecs::reflect::ReflectComponent, reflect::Reflect};
 use serde::{Deserialize, Serialize};
 
 /// Definition of a grid
-#[derive(Clone)]
-#[cfg_attr(feature = "bevy", derive(Component, Default))]
+///
+/// Deserialized grids are validated as by [`CartesianGrid::try_new`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Component))]
 #[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "CartesianGridFields<C>"))]
 pub struct CartesianGrid<C: CoordinateSystem> {
     size_x: u32,
     size_y: u32,
@@ -35,13 +41,64 @@ pub struct CartesianGrid<C: CoordinateSystem> {
     size_xy: u32,
 }
 
+/// Serialized fields of a [`CartesianGrid`], validated when converted to a grid
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "CartesianGrid")]
+struct CartesianGridFields<C> {
+    size_x: u32,
+    size_y: u32,
+    size_z: u32,
+    looping_x: bool,
+    looping_y: bool,
+    looping_z: bool,
+    coord_system: C,
+    #[allow(dead_code)]
+    size_xy: u32,
+}
+
+/// The cached `size_xy` is recomputed instead of trusted.
+#[cfg(feature = "serde")]
+impl<C: CoordinateSystem> TryFrom<CartesianGridFields<C>> for CartesianGrid<C> {
+    type Error = GridError;
+
+    fn try_from(fields: CartesianGridFields<C>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            size_xy: checked_size_xy(fields.size_x, fields.size_y, fields.size_z)?,
+            size_x: fields.size_x,
+            size_y: fields.size_y,
+            size_z: fields.size_z,
+            looping_x: fields.looping_x,
+            looping_y: fields.looping_y,
+            looping_z: fields.looping_z,
+            coord_system: fields.coord_system,
+        })
+    }
+}
+
+/// Creates a 1x1x1 grid without looping, as [`CartesianGridBuilder`] does by default.
+impl<C: CoordinateSystem + Default> Default for CartesianGrid<C> {
+    fn default() -> Self {
+        Self {
+            size_x: 1,
+            size_y: 1,
+            size_z: 1,
+            looping_x: false,
+            looping_y: false,
+            looping_z: false,
+            coord_system: C::default(),
+            size_xy: 1,
+        }
+    }
+}
+
 impl<C: CartesianCoordinates> Grid<C> for CartesianGrid<C> {
-    type Position = CartesianPosition;
+    type Position = C::Position;
 
     /// Returns the total size of the grid
     #[inline]
     fn total_size(&self) -> usize {
-        (self.size_xy * self.size_z).try_into().unwrap()
+        self.volume().try_into().unwrap()
     }
 
     #[inline]
@@ -68,25 +125,48 @@ impl<C: CartesianCoordinates> Grid<C> for CartesianGrid<C> {
         }
     }
 
-    /// Returns a [`CartesianPosition`] from the index of an element in this [`CartesianGrid`].
+    #[inline]
+    fn neighbour(&self, index: GridIndex, direction: Direction) -> Option<GridIndex> {
+        self.get_next_index_in_direction(&CartesianGrid::pos_from_index(self, index), direction)
+    }
+
+    fn in_bounds_neighbour_count(&self, index: GridIndex) -> usize {
+        let pos = CartesianGrid::pos_from_index(self, index);
+        self.coord_system
+            .directions()
+            .iter()
+            .filter(|dir| self.get_next_pos_in_direction(&pos, **dir).is_some())
+            .count()
+    }
+
+    /// Returns the position of an element in this [`CartesianGrid`] from its index.
     ///
     /// Panics if the index is not a valid index.
     #[inline]
-    fn pos_from_index(&self, grid_index: GridIndex) -> CartesianPosition {
-        let index = u32::try_from(grid_index).unwrap();
-        CartesianPosition {
-            x: index % self.size_x,
-            y: (index / self.size_x) % self.size_y,
-            z: index / self.size_xy,
-        }
+    fn pos_from_index(&self, grid_index: GridIndex) -> C::Position {
+        C::position_from_cartesian(CartesianGrid::pos_from_index(self, grid_index))
+    }
+
+    #[inline]
+    fn is_valid_pos(&self, grid_position: &C::Position) -> bool {
+        let pos: CartesianPosition = (*grid_position).into();
+        self.check_coords(pos.x, pos.y, pos.z).is_ok()
+    }
+
+    #[inline]
+    fn try_index_from_pos(&self, grid_position: &C::Position) -> Option<GridIndex> {
+        let pos: CartesianPosition = (*grid_position).into();
+        self.check_coords(pos.x, pos.y, pos.z)
+            .ok()
+            .map(|_| CartesianGrid::index_from_pos(self, &pos))
     }
 
     /// Returns the index from a grid position.
     ///
     /// NO CHECK is done to verify that the given `grid_position` is a valid position for this grid.
     #[inline]
-    fn index_from_pos(&self, grid_position: &CartesianPosition) -> GridIndex {
-        self.index_from_coords(grid_position.x, grid_position.y, grid_position.z)
+    fn index_from_pos(&self, grid_position: &C::Position) -> GridIndex {
+        CartesianGrid::index_from_pos(self, &(*grid_position).into())
     }
 }
 
@@ -113,12 +193,100 @@ impl CartesianGrid<Cartesian2D> {
         Self::new(size_x, size_y, 1, looping_x, looping_y, false, Cartesian2D)
     }
 
+    /// Same as [`CartesianGrid::new_cartesian_2d`] with the sizes and looping flags given as `(x, y)` tuples
+    pub fn from_size_2d(
+        (size_x, size_y): (u32, u32),
+        (looping_x, looping_y): (bool, bool),
+    ) -> CartesianGrid<Cartesian2D> {
+        Self::new_cartesian_2d(size_x, size_y, looping_x, looping_y)
+    }
+
+    /// Returns the number of elements of the grid, computed as a `u64` so that it can not overflow
+    #[inline]
+    pub fn area(&self) -> u64 {
+        u64::from(self.size_x) * u64::from(self.size_y)
+    }
+
+    /// Creates a non-looping grid and its data from a list of rows: `rows[y][x]` is the element at position (`x`, `y`).
+    ///
+    /// The length of the first row defines the size of the grid on the X axis, and all the rows must have this length, otherwise [`GridError::RaggedRows`] is returned.
+    pub fn from_rows<D>(
+        rows: Vec<Vec<D>>,
+    ) -> Result<GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>>, GridError> {
+        let size_x = rows.first().map_or(0, |row| row.len());
+        if let Some((row, ragged)) = rows.iter().enumerate().find(|(_, row)| row.len() != size_x) {
+            return Err(GridError::RaggedRows {
+                row,
+                expected: size_x,
+                got: ragged.len(),
+            });
+        }
+        let (Ok(size_x), Ok(size_y)) = (u32::try_from(size_x), u32::try_from(rows.len())) else {
+            return Err(GridError::DimensionOverflow {
+                size: (
+                    u32::try_from(size_x).unwrap_or(u32::MAX),
+                    u32::try_from(rows.len()).unwrap_or(u32::MAX),
+                    1,
+                ),
+            });
+        };
+        let grid = Self::try_new(size_x, size_y, 1, false, false, false, Cartesian2D)?;
+        Ok(GridData::new(grid, rows.into_iter().flatten().collect()))
+    }
+
+    /// Converts this grid to a grid with the same size and looping flags but a [`Cartesian3D`] coordinate system
+    pub fn into_3d(self) -> CartesianGrid<Cartesian3D> {
+        CartesianGrid {
+            size_x: self.size_x,
+            size_y: self.size_y,
+            size_z: self.size_z,
+            looping_x: self.looping_x,
+            looping_y: self.looping_y,
+            looping_z: self.looping_z,
+            coord_system: Cartesian3D,
+            size_xy: self.size_xy,
+        }
+    }
+
+    /// Returns an iterator over the positions of the grid in an outward spiral: `center`, then each square ring around it, up to a Chebyshev distance of `max_radius`.
+    ///
+    /// Each ring of radius `r` starts at (`center.x + r`, `center.y`) (east) and goes counter-clockwise: first towards Y+, then X-, Y- and X+. Positions outside of the grid are skipped, looping is ignored.
+    ///
+    /// NO CHECK is done to verify that `center` is a valid position for this grid.
+    pub fn spiral(
+        &self,
+        center: CartesianPosition,
+        max_radius: u32,
+    ) -> impl Iterator<Item = CartesianPosition> + '_ {
+        let (cx, cy) = (center.x as i64, center.y as i64);
+        core::iter::once((cx, cy))
+            .chain((1..=max_radius as i64).flat_map(move |r| {
+                // Direction and length of each side of the ring, walked from just below the east position
+                [
+                    (0, 1, r + 1),
+                    (-1, 0, 2 * r),
+                    (0, -1, 2 * r),
+                    (1, 0, 2 * r),
+                    (0, 1, r - 1),
+                ]
+                .into_iter()
+                .scan((cx + r, cy - 1), |pos, (dx, dy, len)| {
+                    let start = *pos;
+                    *pos = (start.0 + dx * len, start.1 + dy * len);
+                    Some((1..=len).map(move |i| (start.0 + dx * i, start.1 + dy * i)))
+                })
+                .flatten()
+            }))
+            .filter(|&(x, y)| x >= 0 && y >= 0 && x < self.size_x as i64 && y < self.size_y as i64)
+            .map(move |(x, y)| CartesianPosition::new(x as u32, y as u32, center.z))
+    }
+
     /// Returns the index from a grid position, ignoring the Z axis.
     ///
     ///  NO CHECK is done to verify that the given position is a valid position for this grid.
     #[inline]
     pub fn get_index_2d(&self, x: u32, y: u32) -> GridIndex {
-        (x + y * self.size_x).try_into().unwrap()
+        (x + y * self.size_x) as GridIndex
     }
 
     /// Returns the index from a grid position, ignoring the Z axis.
@@ -152,10 +320,36 @@ impl CartesianGrid<Cartesian3D> {
             Cartesian3D,
         )
     }
+
+    /// Same as [`CartesianGrid::new_cartesian_3d`] with the sizes and looping flags given as `(x, y, z)` tuples
+    pub fn from_size(
+        (size_x, size_y, size_z): (u32, u32, u32),
+        (looping_x, looping_y, looping_z): (bool, bool, bool),
+    ) -> CartesianGrid<Cartesian3D> {
+        Self::new_cartesian_3d(size_x, size_y, size_z, looping_x, looping_y, looping_z)
+    }
+
+    /// Converts this grid to a grid with the same size and looping flags but a [`Cartesian2D`] coordinate system.
+    ///
+    /// Returns `None` if `size_z` is not 1. The looping flag on the Z axis is dropped.
+    pub fn into_2d(self) -> Option<CartesianGrid<Cartesian2D>> {
+        (self.size_z == 1).then_some(CartesianGrid {
+            size_x: self.size_x,
+            size_y: self.size_y,
+            size_z: 1,
+            looping_x: self.looping_x,
+            looping_y: self.looping_y,
+            looping_z: false,
+            coord_system: Cartesian2D,
+            size_xy: self.size_xy,
+        })
+    }
 }
 
 impl<C: CartesianCoordinates> CartesianGrid<C> {
     /// Creates a new [`CartesianGrid`]
+    ///
+    /// Panics if any size is 0 or if the total size of the grid overflows. See [`CartesianGrid::try_new`] for a fallible version.
     pub fn new(
         size_x: u32,
         size_y: u32,
@@ -165,7 +359,7 @@ impl<C: CartesianCoordinates> CartesianGrid<C> {
         looping_z: bool,
         coord_system: C,
     ) -> CartesianGrid<C> {
-        Self {
+        match Self::try_new(
             size_x,
             size_y,
             size_z,
@@ -173,10 +367,53 @@ impl<C: CartesianCoordinates> CartesianGrid<C> {
             looping_y,
             looping_z,
             coord_system,
-            size_xy: size_x * size_y,
+        ) {
+            Ok(grid) => grid,
+            Err(err) => panic!("{}", err),
         }
     }
 
+    /// Creates a new [`CartesianGrid`]
+    ///
+    /// Returns [`GridError::ZeroDimension`] if any size is 0 and [`GridError::DimensionOverflow`] if the total size of the grid does not fit in the grid index type.
+    pub fn try_new(
+        size_x: u32,
+        size_y: u32,
+        size_z: u32,
+        looping_x: bool,
+        looping_y: bool,
+        looping_z: bool,
+        coord_system: C,
+    ) -> Result<CartesianGrid<C>, GridError> {
+        let size_xy = checked_size_xy(size_x, size_y, size_z)?;
+        Ok(Self {
+            size_x,
+            size_y,
+            size_z,
+            looping_x,
+            looping_y,
+            looping_z,
+            coord_system,
+            size_xy,
+        })
+    }
+
+    /// Changes the size of the grid, keeping its looping flags and coordinate system.
+    ///
+    /// Returns the same errors as [`CartesianGrid::try_new`], in which case the grid is left unchanged.
+    pub(crate) fn set_size(
+        &mut self,
+        size_x: u32,
+        size_y: u32,
+        size_z: u32,
+    ) -> Result<(), GridError> {
+        self.size_xy = checked_size_xy(size_x, size_y, size_z)?;
+        self.size_x = size_x;
+        self.size_y = size_y;
+        self.size_z = size_z;
+        Ok(())
+    }
+
     /// Returns the size of the grid in the X axis.
     #[inline]
     pub fn size_x(&self) -> u32 {
@@ -207,10 +444,77 @@ impl<C: CartesianCoordinates> CartesianGrid<C> {
         (self.size_x, self.size_y, self.size_z)
     }
 
+    /// Returns the position of the center element of the grid: `(size - 1) / 2` on each axis, rounded down for even sizes.
+    pub fn center(&self) -> CartesianPosition {
+        CartesianPosition::new(
+            (self.size_x - 1) / 2,
+            (self.size_y - 1) / 2,
+            (self.size_z - 1) / 2,
+        )
+    }
+
+    /// Returns the positions of the corner elements of the grid, in index order: 4 for a 2d grid and 8 for a 3d grid.
+    ///
+    /// Corners which coincide, on axes with a size of 1, are only returned once.
+    pub fn corners(&self) -> Vec<CartesianPosition> {
+        let ends = |size: u32| [0, size - 1];
+        let mut corners = Vec::with_capacity(8);
+        for z in ends(self.size_z) {
+            for y in ends(self.size_y) {
+                for x in ends(self.size_x) {
+                    corners.push(CartesianPosition::new(x, y, z));
+                }
+            }
+        }
+        corners.sort_unstable();
+        corners.dedup();
+        corners
+    }
+
+    /// Returns the number of elements of the grid, computed as a `u64` so that it can not overflow
+    #[inline]
+    pub fn volume(&self) -> u64 {
+        u64::from(self.size_x) * u64::from(self.size_y) * u64::from(self.size_z)
+    }
+
+    /// Returns the looping flags of this grid as a tuple
+    #[inline]
+    pub fn looping(&self) -> (bool, bool, bool) {
+        (self.looping_x, self.looping_y, self.looping_z)
+    }
+
+    /// Returns a copy of this grid with the given `(x, y, z)` looping flags
+    pub fn with_looping(mut self, (looping_x, looping_y, looping_z): (bool, bool, bool)) -> Self {
+        self.looping_x = looping_x;
+        self.looping_y = looping_y;
+        self.looping_z = looping_z;
+        self
+    }
+
+    /// Returns the size of the grid on `axis`
+    #[inline]
+    pub fn axis_size(&self, axis: Axis) -> u32 {
+        match axis {
+            Axis::X => self.size_x,
+            Axis::Y => self.size_y,
+            Axis::Z => self.size_z,
+        }
+    }
+
+    /// Returns whether or not the grid loops on `axis`
+    #[inline]
+    pub fn axis_looping(&self, axis: Axis) -> bool {
+        match axis {
+            Axis::X => self.looping_x,
+            Axis::Y => self.looping_y,
+            Axis::Z => self.looping_z,
+        }
+    }
+
     /// Returns a [`Range`] over all indexes in this grid
     #[inline]
     pub fn indexes(&self) -> Range<GridIndex> {
-        0..self.total_size()
+        0..self.total_size() as GridIndex
     }
 
     /// Returns all the the [`CoordinateSystem`] used by this [`CartesianGrid`]
@@ -219,12 +523,29 @@ impl<C: CartesianCoordinates> CartesianGrid<C> {
         &self.coord_system
     }
 
+    /// Returns the signed offset to add to the index of an element to get the index of its neighbour in `direction`: `1` for [`Direction::XForward`], `size_x` for [`Direction::YForward`], `size_xy` for [`Direction::ZForward`] and their opposites for the backward directions.
+    ///
+    /// This ignores bounds and looping: the result is only meaningful for elements that are not on the face of the grid in `direction`.
+    #[inline]
+    pub fn index_delta(&self, direction: Direction) -> i64 {
+        match direction {
+            Direction::XForward => 1,
+            Direction::XBackward => -1,
+            Direction::YForward => i64::from(self.size_x),
+            Direction::YBackward => -i64::from(self.size_x),
+            Direction::ZForward => i64::from(self.size_xy),
+            Direction::ZBackward => -i64::from(self.size_xy),
+        }
+    }
+
     /// Returns the index from a grid position.
     ///
     /// NO CHECK is done to verify that the given position is a valid position for this grid.
     #[inline]
     pub fn index_from_coords(&self, x: u32, y: u32, z: u32) -> GridIndex {
-        (x + y * self.size_x + z * self.size_xy).try_into().unwrap()
+        (u64::from(x)
+            + u64::from(y) * u64::from(self.size_x)
+            + u64::from(z) * u64::from(self.size_xy)) as GridIndex
     }
 
     /// Returns the index from a grid position.
@@ -240,12 +561,28 @@ impl<C: CartesianCoordinates> CartesianGrid<C> {
     /// Panics if the index is not a valid index.
     #[inline]
     pub fn pos_from_index(&self, grid_index: GridIndex) -> CartesianPosition {
-        let index = u32::try_from(grid_index).unwrap();
+        let index = grid_index as u64;
+        let (size_x, size_y) = (u64::from(self.size_x), u64::from(self.size_y));
         CartesianPosition {
-            x: index % self.size_x,
-            y: (index / self.size_x) % self.size_y,
-            z: index / self.size_xy,
+            x: (index % size_x) as u32,
+            y: ((index / size_x) % size_y) as u32,
+            z: u32::try_from(index / u64::from(self.size_xy)).unwrap(),
+        }
+    }
+
+    /// Returns the next element's indexes in each direction, without allocating.
+    ///
+    /// Same as [`Grid::get_neighbours_in_all_directions`] but with a fixed-size array: `N` should be >= to the directions count of the coordinate system (4 for [`Cartesian2D`], 6 for [`Cartesian3D`]), this panics otherwise.
+    pub fn get_neighbours_array<const N: usize>(
+        &self,
+        grid_index: GridIndex,
+    ) -> [Option<GridIndex>; N] {
+        let pos = self.pos_from_index(grid_index);
+        let mut neighbours = [None; N];
+        for dir in self.coord_system.directions() {
+            neighbours[usize::from(*dir)] = self.get_next_index_in_direction(&pos, *dir);
         }
+        neighbours
     }
 
     /// Returns the index of the next position in the grid when moving 1 unit in `direction` from `grid_position`.
@@ -259,10 +596,50 @@ impl<C: CartesianCoordinates> CartesianGrid<C> {
         direction: Direction,
     ) -> Option<GridIndex> {
         let delta = &self.coord_system.deltas()[direction as usize];
-        match self.get_next_pos(grid_position, &delta) {
-            Some(next_pos) => Some(self.index_from_pos(&next_pos)),
-            None => None,
-        }
+        self.get_next_pos(grid_position, delta)
+            .map(|next_pos| self.index_from_pos(&next_pos))
+    }
+
+    /// Returns the index of the neighbour of `grid_position` in `direction`, clamped to the grid: on a non-looping axis, a move out of the grid stays on the edge element. Moves on looping axes wrap as usual.
+    ///
+    /// Returns `None` if `direction` is not a direction of the coordinate system, such as a Z direction in a [`Cartesian2D`] grid.
+    ///
+    /// NO CHECK is done to verify that the given `grid_position` is a valid position for this grid.
+    pub fn get_neighbour_clamped(
+        &self,
+        grid_position: &CartesianPosition,
+        direction: Direction,
+    ) -> Option<GridIndex> {
+        let delta = self.coord_system.deltas().get(direction as usize)?;
+        let next_pos = grid_position.get_delta_position(delta);
+        let [x, y, z] = [
+            (next_pos.0, Axis::X),
+            (next_pos.1, Axis::Y),
+            (next_pos.2, Axis::Z),
+        ]
+        .map(|(coord, axis)| {
+            let size = i64::from(self.axis_size(axis));
+            match self.axis_looping(axis) {
+                true => coord.rem_euclid(size) as u32,
+                false => coord.clamp(0, size - 1) as u32,
+            }
+        });
+        Some(self.index_from_coords(x, y, z))
+    }
+
+    /// Same as [`CartesianGrid::get_next_index_in_direction`] but also returns `true` if the move wrapped around a looping axis.
+    ///
+    /// Returns `None` if the destination is not in the grid.
+    ///
+    /// NO CHECK is done to verify that the given `grid_position` is a valid position for this grid.
+    pub fn get_next_index_in_direction_wrapped(
+        &self,
+        grid_position: &CartesianPosition,
+        direction: Direction,
+    ) -> Option<(GridIndex, bool)> {
+        let delta = &self.coord_system.deltas()[direction as usize];
+        self.get_next_pos_wrapped(grid_position, delta)
+            .map(|(next_pos, wrapped)| (self.index_from_pos(&next_pos), wrapped))
     }
 
     /// Returns the index of the next position in the grid when moving `units` in `direction` from `grid_position`.
@@ -276,11 +653,9 @@ impl<C: CartesianCoordinates> CartesianGrid<C> {
         direction: Direction,
         units: i32,
     ) -> Option<GridIndex> {
-        let delta = self.coord_system.deltas()[direction as usize].clone() * units;
-        match self.get_next_pos(grid_position, &delta) {
-            Some(next_pos) => Some(self.index_from_pos(&next_pos)),
-            None => None,
-        }
+        let delta = self.coord_system.deltas()[direction as usize] * units;
+        self.get_next_pos(grid_position, &delta)
+            .map(|next_pos| self.index_from_pos(&next_pos))
     }
 
     /// Returns the the next position in the grid when moving 1 unit in `direction` from `grid_position`.
@@ -294,10 +669,84 @@ impl<C: CartesianCoordinates> CartesianGrid<C> {
         direction: Direction,
     ) -> Option<CartesianPosition> {
         let delta = &self.coord_system.deltas()[direction as usize];
-        match self.get_next_pos(grid_position, &delta) {
-            Some(next_pos) => Some(next_pos),
-            None => None,
-        }
+        self.get_next_pos(grid_position, delta)
+    }
+
+    /// Returns an iterator over the directions of the coordinate system and the positions of the neighbours of `grid_position` in those directions.
+    ///
+    /// Neighbours outside of the grid are skipped, looping is respected.
+    ///
+    /// NO CHECK is done to verify that the given `grid_position` is a valid position for this grid.
+    pub fn neighbour_positions(
+        &self,
+        grid_position: &CartesianPosition,
+    ) -> impl Iterator<Item = (Direction, CartesianPosition)> + '_ {
+        let grid_position = *grid_position;
+        self.coord_system
+            .directions()
+            .iter()
+            .filter_map(move |direction| {
+                self.get_next_pos_in_direction(&grid_position, *direction)
+                    .map(|next_pos| (*direction, next_pos))
+            })
+    }
+
+    /// Fills `neighbours_buffer` with the neighbours of the element at `grid_index` in the given `directions` only: `neighbours_buffer[i]` is the neighbour in `directions[i]`, or `None` if there is no neighbour in this direction.
+    ///
+    /// The buffer is cleared first and ends up with the length of `directions`, so that it can be reused between calls without reallocating.
+    ///
+    /// Panics if one of the `directions` is not a direction of the coordinate system of the grid. NO CHECK is done to verify that the given index is a valid index for this grid.
+    pub fn get_neighbours_in_directions(
+        &self,
+        grid_index: GridIndex,
+        directions: &[Direction],
+        neighbours_buffer: &mut Vec<Option<GridIndex>>,
+    ) {
+        let pos = self.pos_from_index(grid_index);
+        neighbours_buffer.clear();
+        neighbours_buffer.extend(
+            directions
+                .iter()
+                .map(|dir| self.get_next_index_in_direction(&pos, *dir)),
+        );
+    }
+
+    /// Returns an iterator over the positions of a Bresenham-style line from `from` to `to`, both included: each step moves by one unit on the axis with the largest extent, and by at most one unit on the other axes.
+    ///
+    /// The line does not wrap around looping axes. NO CHECK is done to verify that the given positions are valid positions for this grid.
+    pub fn line(
+        &self,
+        from: &CartesianPosition,
+        to: &CartesianPosition,
+    ) -> impl Iterator<Item = CartesianPosition> {
+        let start = from.to_array().map(i64::from);
+        let end = to.to_array().map(i64::from);
+        let deltas = [end[0] - start[0], end[1] - start[1], end[2] - start[2]];
+        let steps = deltas.iter().map(|d| d.abs()).max().unwrap_or(0);
+        (0..=steps).map(move |step| {
+            // Rounds `step * delta / steps` to the nearest integer
+            let coord = |axis: usize| match steps {
+                0 => start[axis],
+                _ => start[axis] + (2 * step * deltas[axis] + steps).div_euclid(2 * steps),
+            };
+            CartesianPosition::new(coord(0) as u32, coord(1) as u32, coord(2) as u32)
+        })
+    }
+
+    /// Returns an iterator over the positions of the lines (see [`CartesianGrid::line`]) joining each waypoint to the next one.
+    ///
+    /// The position shared by two consecutive lines is only returned once. An empty `waypoints` slice gives an empty path and a single waypoint gives a path made of that position.
+    ///
+    /// NO CHECK is done to verify that the given positions are valid positions for this grid.
+    pub fn path<'a>(
+        &'a self,
+        waypoints: &'a [CartesianPosition],
+    ) -> impl Iterator<Item = CartesianPosition> + 'a {
+        waypoints.first().copied().into_iter().chain(
+            waypoints
+                .windows(2)
+                .flat_map(|pair| self.line(&pair[0], &pair[1]).skip(1)),
+        )
     }
 
     /// Returns the next position in the grid when moving `delta` unit(s) in `direction` from `grid_position`.
@@ -310,19 +759,32 @@ impl<C: CartesianCoordinates> CartesianGrid<C> {
         grid_position: &CartesianPosition,
         delta: &GridDelta,
     ) -> Option<CartesianPosition> {
-        let mut next_pos = grid_position.get_delta_position(&delta);
-        for (looping, pos, size) in vec![
+        self.get_next_pos_wrapped(grid_position, delta)
+            .map(|(next_pos, _)| next_pos)
+    }
+
+    /// Same as [`CartesianGrid::get_next_pos`] but also returns `true` if the move wrapped around any looping axis.
+    ///
+    /// Returns `None` if the destination is not in the grid.
+    ///
+    /// NO CHECK is done to verify that the given `grid_position` is a valid position for this grid.
+    pub fn get_next_pos_wrapped(
+        &self,
+        grid_position: &CartesianPosition,
+        delta: &GridDelta,
+    ) -> Option<(CartesianPosition, bool)> {
+        let mut next_pos = grid_position.get_delta_position(delta);
+        let mut wrapped = false;
+        for (looping, pos, size) in [
             (self.looping_x, &mut next_pos.0, self.size_x),
             (self.looping_y, &mut next_pos.1, self.size_y),
             (self.looping_z, &mut next_pos.2, self.size_z),
         ] {
             match looping {
                 true => {
-                    if *pos < 0 {
-                        *pos += size as i64
-                    }
-                    if *pos >= size as i64 {
-                        *pos -= size as i64
+                    if *pos < 0 || *pos >= size as i64 {
+                        *pos = pos.rem_euclid(size as i64);
+                        wrapped = true;
                     }
                 }
                 false => {
@@ -332,13 +794,339 @@ impl<C: CartesianCoordinates> CartesianGrid<C> {
                 }
             }
         }
-        Some(CartesianPosition {
-            x: u32::try_from(next_pos.0).unwrap(),
-            y: u32::try_from(next_pos.1).unwrap(),
-            z: u32::try_from(next_pos.2).unwrap(),
+        Some((
+            CartesianPosition {
+                x: u32::try_from(next_pos.0).unwrap(),
+                y: u32::try_from(next_pos.1).unwrap(),
+                z: u32::try_from(next_pos.2).unwrap(),
+            },
+            wrapped,
+        ))
+    }
+
+    /// Returns the index reached when moving `delta` from `index`, wrapping on looping axes.
+    ///
+    /// Equivalent to [`CartesianGrid::get_next_pos`] followed by [`CartesianGrid::index_from_pos`], but works directly on the index without building intermediate positions.
+    ///
+    /// Returns `None` if the destination is not in the grid.
+    ///
+    /// NO CHECK is done to verify that the given `index` is a valid index for this grid.
+    pub fn wrap_index(&self, index: GridIndex, delta: &GridDelta) -> Option<GridIndex> {
+        let index = index as i64;
+        let (size_x, size_xy) = (self.size_x as i64, self.size_xy as i64);
+        let mut next_index = index;
+        for (looping, coord, size, stride, d) in [
+            (self.looping_x, index % size_x, size_x, 1, delta.dx),
+            (
+                self.looping_y,
+                (index / size_x) % self.size_y as i64,
+                self.size_y as i64,
+                size_x,
+                delta.dy,
+            ),
+            (
+                self.looping_z,
+                index / size_xy,
+                self.size_z as i64,
+                size_xy,
+                delta.dz,
+            ),
+        ] {
+            let mut next = coord + d as i64;
+            if !(0..size).contains(&next) {
+                if !looping {
+                    return None;
+                }
+                next = next.rem_euclid(size);
+            }
+            next_index += (next - coord) * stride;
+        }
+        Some(next_index as GridIndex)
+    }
+
+    /// Returns an iterator over the positions reached by repeatedly moving 1 unit in `direction` from `from`, `from` excluded.
+    ///
+    /// Stops at the edge of the grid on a non-looping axis. On a looping axis, stops before coming back to `from`, so each position is returned once.
+    ///
+    /// NO CHECK is done to verify that `from` is a valid position for this grid.
+    pub fn cells_along(
+        &self,
+        from: CartesianPosition,
+        direction: Direction,
+    ) -> impl Iterator<Item = CartesianPosition> + '_ {
+        core::iter::successors(
+            self.get_next_pos_in_direction(&from, direction),
+            move |pos| self.get_next_pos_in_direction(pos, direction),
+        )
+        .take_while(move |pos| *pos != from)
+        .take(self.total_size())
+    }
+
+    /// Walks the grid from `origin` by steps of `delta` and returns the first position (and its index) for which `hit` returns `true`.
+    ///
+    /// `origin` itself is not tested. Returns `None` if no position was hit after `max_steps` steps, or if the ray leaves the grid. On looping axes the ray wraps around, so `max_steps` is what stops it on a fully looping grid.
+    ///
+    /// NO CHECK is done to verify that `origin` is a valid position for this grid.
+    pub fn raycast<F: Fn(GridIndex) -> bool>(
+        &self,
+        origin: CartesianPosition,
+        delta: GridDelta,
+        max_steps: u32,
+        hit: F,
+    ) -> Option<(CartesianPosition, GridIndex)> {
+        let mut pos = origin;
+        for _ in 0..max_steps {
+            pos = self.get_next_pos(&pos, &delta)?;
+            let index = self.index_from_pos(&pos);
+            if hit(index) {
+                return Some((pos, index));
+            }
+        }
+        None
+    }
+
+    /// Returns `pos` with each coordinate clamped to the bounds of the grid, ignoring looping.
+    ///
+    /// See [`CartesianGrid::wrap_pos`] to wrap the coordinates on looping axes instead.
+    pub fn clamp_pos(&self, pos: CartesianPosition) -> CartesianPosition {
+        CartesianPosition {
+            x: pos.x.min(self.size_x.saturating_sub(1)),
+            y: pos.y.min(self.size_y.saturating_sub(1)),
+            z: pos.z.min(self.size_z.saturating_sub(1)),
+        }
+    }
+
+    /// Returns `pos` with each coordinate wrapped on looping axes, and clamped to the bounds of the grid on non-looping axes.
+    ///
+    /// Grids created through [`CartesianGrid::try_new`] never have a size of 0. Should an axis still have a size of 0, its coordinate is set to 0, as [`CartesianGrid::clamp_pos`] does, instead of dividing by 0.
+    pub fn wrap_pos(&self, pos: CartesianPosition) -> CartesianPosition {
+        let wrap = |coord: u32, size: u32, looping: bool| match looping {
+            true => coord.checked_rem(size).unwrap_or(0),
+            false => coord.min(size.saturating_sub(1)),
+        };
+        CartesianPosition {
+            x: wrap(pos.x, self.size_x, self.looping_x),
+            y: wrap(pos.y, self.size_y, self.looping_y),
+            z: wrap(pos.z, self.size_z, self.looping_z),
+        }
+    }
+
+    /// Returns the index of the element facing the element at `index` across the wrap-around seam of `axis`: for an element on the min face of the axis, the element with the same other coordinates on the max face, and the other way around.
+    ///
+    /// Returns `None` if the grid does not loop on `axis` or if the element is on neither face. On an axis of size 1, the element is its own partner.
+    ///
+    /// NO CHECK is done to verify that the given index is a valid index for this grid.
+    pub fn wrap_partner(&self, index: GridIndex, axis: Axis) -> Option<GridIndex> {
+        if !self.axis_looping(axis) {
+            return None;
+        }
+        let mut pos = self.pos_from_index(index);
+        let coord = match axis {
+            Axis::X => &mut pos.x,
+            Axis::Y => &mut pos.y,
+            Axis::Z => &mut pos.z,
+        };
+        let max = self.axis_size(axis) - 1;
+        *coord = match *coord {
+            0 => max,
+            c if c == max => 0,
+            _ => return None,
+        };
+        Some(self.index_from_pos(&pos))
+    }
+
+    /// Returns an error if the position is not in the grid
+    pub(crate) fn check_coords(&self, x: u32, y: u32, z: u32) -> Result<(), GridError> {
+        match x < self.size_x && y < self.size_y && z < self.size_z {
+            true => Ok(()),
+            false => Err(GridError::PositionOutOfBounds {
+                position: (x, y, z),
+                size: self.size(),
+            }),
+        }
+    }
+
+    /// Returns `true` if the coordinate system has directions along `axis`
+    pub(crate) fn uses_axis(&self, axis: Axis) -> bool {
+        self.coord_system
+            .directions()
+            .iter()
+            .any(|dir| dir.axis() == axis)
+    }
+
+    /// Returns an iterator over the indexes of the neighbours of the element at `index` in the given `neighbourhood`, instead of the directions of the coordinate system.
+    ///
+    /// The neighbourhood is 2d if the coordinate system has no direction along the Z axis. Neighbours outside of the grid are skipped, looping is respected.
+    ///
+    /// Each neighbour is returned once and the element itself is never returned: on looping axes of size 1 or 2, several deltas can lead to the same element.
+    ///
+    /// NO CHECK is done to verify that the given `index` is a valid index for this grid.
+    pub fn neighbours_in(
+        &self,
+        index: GridIndex,
+        neighbourhood: Neighbourhood,
+    ) -> impl Iterator<Item = GridIndex> + '_ {
+        self.distinct_neighbours(index, neighbourhood.deltas(self.uses_axis(Axis::Z)).iter())
+    }
+
+    /// Returns an iterator over the indexes of the diagonal neighbours of the element at `index`: the edge and corner neighbours, reached by a delta with two or three non-zero components.
+    ///
+    /// For a 3d grid this yields up to 20 neighbours, and up to 4 for a 2d grid. Face neighbours are not included, see [`Grid::get_neighbours_in_all_directions`]. Neighbours outside of the grid are skipped, looping is respected. As in [`CartesianGrid::neighbours_in`], each neighbour is returned once and the element itself is never returned.
+    ///
+    /// NO CHECK is done to verify that the given `index` is a valid index for this grid.
+    pub fn diagonal_neighbours(&self, index: GridIndex) -> impl Iterator<Item = GridIndex> + '_ {
+        self.distinct_neighbours(
+            index,
+            Neighbourhood::Moore
+                .deltas(self.uses_axis(Axis::Z))
+                .iter()
+                .filter(|delta| !delta.is_unit()),
+        )
+    }
+
+    /// Returns the indexes reached from `index` by `deltas`, in order, without duplicates and without `index` itself
+    fn distinct_neighbours<'a>(
+        &self,
+        index: GridIndex,
+        deltas: impl Iterator<Item = &'a GridDelta>,
+    ) -> vec::IntoIter<GridIndex> {
+        let mut neighbours = Vec::new();
+        for neighbour in deltas.filter_map(|delta| self.wrap_index(index, delta)) {
+            if neighbour != index && !neighbours.contains(&neighbour) {
+                neighbours.push(neighbour);
+            }
+        }
+        neighbours.into_iter()
+    }
+
+    /// Returns an iterator over the indexes of all the elements on the outer faces of the grid, in increasing order and each one once: perimeter elements for a 2d grid and surface elements for a 3d grid.
+    ///
+    /// Looping axes have no edge on that axis, so a fully looping grid has no edge element. Axes without any direction in the coordinate system (Z for [`Cartesian2D`]) are ignored.
+    pub fn edge_indices(&self) -> impl Iterator<Item = GridIndex> + '_ {
+        let has_edge = |axis: Axis, looping: bool| !looping && self.uses_axis(axis);
+        let edge_x = has_edge(Axis::X, self.looping_x);
+        let edge_y = has_edge(Axis::Y, self.looping_y);
+        let edge_z = has_edge(Axis::Z, self.looping_z);
+        let on_edge = |edge: bool, coord: u32, size: u32| edge && (coord == 0 || coord == size - 1);
+
+        (0..self.size_z).flat_map(move |z| {
+            (0..self.size_y).flat_map(move |y| {
+                let full_row = on_edge(edge_y, y, self.size_y) || on_edge(edge_z, z, self.size_z);
+                let row_start = self.index_from_coords(0, y, z);
+                // Elements of the row on an edge: `0..first_end` then `second_start..size_x`
+                let (first_end, second_start) = match (full_row, edge_x) {
+                    (true, _) => (self.size_x, self.size_x),
+                    (false, true) => (1, (self.size_x - 1).max(1)),
+                    (false, false) => (0, self.size_x),
+                };
+                (0..first_end)
+                    .chain(second_start..self.size_x)
+                    .map(move |x| row_start + x as GridIndex)
+            })
+        })
+    }
+
+    /// Returns an iterator over all the positions of the grid at a Chebyshev distance of exactly `radius` from `center`: the boundary of the square (or cube) of half-size `radius` centered on `center`.
+    ///
+    /// Only positions in the grid are returned, each one once. On looping axes, distances are measured around the loop. A `radius` of 0 only yields `center`.
+    ///
+    /// NO CHECK is done to verify that `center` is a valid position for this grid.
+    pub fn ring(
+        &self,
+        center: CartesianPosition,
+        radius: u32,
+    ) -> impl Iterator<Item = CartesianPosition> {
+        self.positions_within_box(center, radius)
+            .filter_map(move |(pos, (dx, dy, dz))| (dx.max(dy).max(dz) == radius).then_some(pos))
+    }
+
+    /// Returns an iterator over all the positions of the grid at a Chebyshev distance lower or equal to `radius` from `center`: the square (or cube) of half-size `radius` centered on `center`.
+    ///
+    /// Only positions in the grid are returned, each one once. On looping axes, distances are measured around the loop.
+    ///
+    /// NO CHECK is done to verify that `center` is a valid position for this grid.
+    pub fn disk(
+        &self,
+        center: CartesianPosition,
+        radius: u32,
+    ) -> impl Iterator<Item = CartesianPosition> {
+        self.positions_within_box(center, radius)
+            .map(|(pos, _)| pos)
+    }
+
+    /// Returns an iterator over the indexes of the elements of the grid within `radius` of `center` according to `metric`, in index order.
+    ///
+    /// `center` itself is only included if `include_center` is `true`. On looping axes, distances are measured around the loop.
+    ///
+    /// NO CHECK is done to verify that `center` is a valid position for this grid.
+    pub fn neighbours_within(
+        &self,
+        center: CartesianPosition,
+        radius: u32,
+        metric: DistanceMetric,
+        include_center: bool,
+    ) -> impl Iterator<Item = GridIndex> + '_ {
+        self.positions_within_box(center, radius)
+            .filter(move |(_, distances)| {
+                metric.is_within(*distances, radius) && (include_center || *distances != (0, 0, 0))
+            })
+            .map(|(pos, _)| self.index_from_pos(&pos))
+    }
+
+    /// Returns the positions within a Chebyshev distance of `radius` of `center`, with their distance to `center` on each axis, in index order.
+    fn positions_within_box(
+        &self,
+        center: CartesianPosition,
+        radius: u32,
+    ) -> impl Iterator<Item = (CartesianPosition, (u32, u32, u32))> {
+        let xs = axis_coords_within(center.x, self.size_x, self.looping_x, radius);
+        let ys = axis_coords_within(center.y, self.size_y, self.looping_y, radius);
+        let zs = axis_coords_within(center.z, self.size_z, self.looping_z, radius);
+        zs.into_iter().flat_map(move |(z, dz)| {
+            let xs = xs.clone();
+            ys.clone().into_iter().flat_map(move |(y, dy)| {
+                xs.clone()
+                    .into_iter()
+                    .map(move |(x, dx)| (CartesianPosition { x, y, z }, (dx, dy, dz)))
+            })
         })
     }
 
+    /// Returns an iterator over all the positions of the grid in index order, computed incrementally.
+    ///
+    /// Prefer it to calling [`CartesianGrid::pos_from_index`] on each index when walking the whole grid: it avoids two divisions and a modulo per position, which makes a full pass several times faster. The `positions` benchmark (`cargo bench --bench positions`) compares both.
+    pub fn position_walker(&self) -> PositionWalker {
+        PositionWalker {
+            next: CartesianPosition::new(0, 0, 0),
+            size_x: self.size_x,
+            size_y: self.size_y,
+            remaining: self.total_size(),
+        }
+    }
+
+    /// Returns the indexes of the neighbours of all the elements of the grid in a flat buffer of size [`Grid::total_directional_size`]: the neighbour of the element at `index` in the direction of index `dir_index` is at [`Grid::directional_index`].
+    ///
+    /// Each entry is the same as in [`Grid::get_neighbours_in_all_directions`]: `None` for neighbours outside of the grid, looping is respected.
+    pub fn build_neighbour_offset_table(&self) -> Vec<Option<GridIndex>> {
+        let directions = self.coord_system.directions();
+        let mut table = Vec::with_capacity(self.total_directional_size());
+        for pos in self.position_walker() {
+            table.extend(
+                directions
+                    .iter()
+                    .map(|direction| self.get_next_index_in_direction(&pos, *direction)),
+            );
+        }
+        table
+    }
+
+    /// Returns the positions of all the elements of the grid, the position of the element at index `i` being at index `i`.
+    ///
+    /// The cache takes 12 bytes per element and building it costs a full pass of [`CartesianGrid::position_walker`]. It is only worth building when positions of arbitrary indexes are decoded many times on a grid whose cache fits in the CPU caches: for larger grids, loading a position from memory is slower than decoding it with [`CartesianGrid::pos_from_index`]. For full passes, [`CartesianGrid::position_walker`] is about as fast as reading the cache and does not allocate. The `positions` benchmark (`cargo bench --bench positions`) compares these access patterns.
+    pub fn positions_cache(&self) -> Vec<CartesianPosition> {
+        self.position_walker().collect()
+    }
+
     /// Creates a default [`GridData`] with the size of the [`CartesianGrid`] with each element value set to its default one.
     pub fn default_grid_data<D: Default + Clone>(&self) -> GridData<C, D, CartesianGrid<C>> {
         GridData::new(self.clone(), vec![D::default(); self.total_size()])
@@ -348,6 +1136,268 @@ impl<C: CartesianCoordinates> CartesianGrid<C> {
     pub fn new_grid_data<D: Clone>(&self, element: D) -> GridData<C, D, CartesianGrid<C>> {
         GridData::new(self.clone(), vec![element; self.total_size()])
     }
+
+    /// Same as [`CartesianGrid::default_grid_data`] but creates a [`GridDataRef`] borrowing this grid instead of cloning it.
+    pub fn default_grid_data_ref<D: Default + Clone>(
+        &self,
+    ) -> GridDataRef<'_, C, D, CartesianGrid<C>> {
+        GridDataRef::new(self, vec![D::default(); self.total_size()])
+    }
+
+    /// Same as [`CartesianGrid::new_grid_data`] but creates a [`GridDataRef`] borrowing this grid instead of cloning it.
+    pub fn new_grid_data_ref<D: Clone>(
+        &self,
+        element: D,
+    ) -> GridDataRef<'_, C, D, CartesianGrid<C>> {
+        GridDataRef::new(self, vec![element; self.total_size()])
+    }
+
+    /// Creates a [`GridData`] with the size of the [`CartesianGrid`] with each element value being the result of `f` called on the element's position.
+    ///
+    /// `f` is called for each position in index order.
+    pub fn grid_data_from_fn<D, F: FnMut(CartesianPosition) -> D>(
+        &self,
+        mut f: F,
+    ) -> GridData<C, D, CartesianGrid<C>> {
+        let data = self
+            .indexes()
+            .map(|index| f(self.pos_from_index(index)))
+            .collect();
+        GridData::new(self.clone(), data)
+    }
+}
+
+/// Returns all the coordinates on an axis within `radius` of `center`, in increasing order, with their distance to `center`.
+///
+/// On a looping axis, the distance is measured around the loop and each coordinate is only returned once.
+fn axis_coords_within(center: u32, size: u32, looping: bool, radius: u32) -> Vec<(u32, u32)> {
+    if looping {
+        if u64::from(radius) * 2 + 1 >= u64::from(size) {
+            (0..size)
+                .map(|coord| {
+                    let offset = (coord + size - center) % size;
+                    (coord, offset.min(size - offset))
+                })
+                .collect()
+        } else {
+            let mut coords: Vec<(u32, u32)> = (0..=2 * radius)
+                .map(|k| {
+                    let offset = k as i64 - radius as i64;
+                    let coord = (center as i64 + offset).rem_euclid(size as i64) as u32;
+                    (coord, offset.unsigned_abs() as u32)
+                })
+                .collect();
+            coords.sort_unstable();
+            coords
+        }
+    } else {
+        let min = center.saturating_sub(radius);
+        let max = center.saturating_add(radius).min(size.saturating_sub(1));
+        (min..=max)
+            .map(|coord| (coord, coord.abs_diff(center)))
+            .collect()
+    }
+}
+
+/// Iterator over all the positions of a [`CartesianGrid`] in index order, see [`CartesianGrid::position_walker`].
+///
+/// Positions are computed incrementally, without the divisions done by [`CartesianGrid::pos_from_index`].
+#[derive(Clone, Debug)]
+pub struct PositionWalker {
+    next: CartesianPosition,
+    size_x: u32,
+    size_y: u32,
+    remaining: usize,
+}
+
+impl Iterator for PositionWalker {
+    type Item = CartesianPosition;
+
+    #[inline]
+    fn next(&mut self) -> Option<CartesianPosition> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let pos = self.next;
+        self.next.x += 1;
+        if self.next.x == self.size_x {
+            self.next.x = 0;
+            self.next.y += 1;
+            if self.next.y == self.size_y {
+                self.next.y = 0;
+                self.next.z += 1;
+            }
+        }
+        Some(pos)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for PositionWalker {}
+
+impl core::iter::FusedIterator for PositionWalker {}
+
+/// Validates the size of a grid and returns the value of `size_x` * `size_y`.
+///
+/// Returns [`GridError::ZeroDimension`] if any size is 0 and [`GridError::DimensionOverflow`] if the total size of the grid does not fit in the grid index type.
+pub(crate) fn checked_size_xy(size_x: u32, size_y: u32, size_z: u32) -> Result<u32, GridError> {
+    let size = (size_x, size_y, size_z);
+    if size_x == 0 || size_y == 0 || size_z == 0 {
+        return Err(GridError::ZeroDimension { size });
+    }
+    let size_xy = size_x
+        .checked_mul(size_y)
+        .ok_or(GridError::DimensionOverflow { size })?;
+    // The total size is computed as a `u64` so that it can not overflow before being checked against the index type
+    GridIndex::try_from(u64::from(size_xy) * u64::from(size_z))
+        .map_err(|_| GridError::DimensionOverflow { size })?;
+    Ok(size_xy)
+}
+
+/// Builder for a [`CartesianGrid`], with sizes defaulting to 1 and no looping.
+#[derive(Clone, Debug)]
+pub struct CartesianGridBuilder<C: CartesianCoordinates> {
+    size: (u32, u32, u32),
+    looping: (bool, bool, bool),
+    coord_system: C,
+}
+
+impl<C: CartesianCoordinates + Default> Default for CartesianGridBuilder<C> {
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+impl<C: CartesianCoordinates + Default> CartesianGrid<C> {
+    /// Returns a [`CartesianGridBuilder`] using the default value of the coordinate system.
+    pub fn builder() -> CartesianGridBuilder<C> {
+        CartesianGridBuilder::default()
+    }
+}
+
+impl<C: CartesianCoordinates> CartesianGridBuilder<C> {
+    /// Creates a new builder for a grid using `coord_system`
+    pub fn new(coord_system: C) -> Self {
+        Self {
+            size: (1, 1, 1),
+            looping: (false, false, false),
+            coord_system,
+        }
+    }
+
+    /// Sets the coordinate system of the grid
+    pub fn coord_system<C2: CartesianCoordinates>(
+        self,
+        coord_system: C2,
+    ) -> CartesianGridBuilder<C2> {
+        CartesianGridBuilder {
+            size: self.size,
+            looping: self.looping,
+            coord_system,
+        }
+    }
+
+    /// Sets the size of the grid on the X axis
+    pub fn size_x(mut self, size_x: u32) -> Self {
+        self.size.0 = size_x;
+        self
+    }
+
+    /// Sets the size of the grid on the Y axis
+    pub fn size_y(mut self, size_y: u32) -> Self {
+        self.size.1 = size_y;
+        self
+    }
+
+    /// Sets the size of the grid on the Z axis
+    pub fn size_z(mut self, size_z: u32) -> Self {
+        self.size.2 = size_z;
+        self
+    }
+
+    /// Sets the size of the grid on all axes
+    pub fn size(mut self, size_x: u32, size_y: u32, size_z: u32) -> Self {
+        self.size = (size_x, size_y, size_z);
+        self
+    }
+
+    /// Sets whether the X axis loops
+    pub fn looping_x(mut self, looping: bool) -> Self {
+        self.looping.0 = looping;
+        self
+    }
+
+    /// Sets whether the Y axis loops
+    pub fn looping_y(mut self, looping: bool) -> Self {
+        self.looping.1 = looping;
+        self
+    }
+
+    /// Sets whether the Z axis loops
+    pub fn looping_z(mut self, looping: bool) -> Self {
+        self.looping.2 = looping;
+        self
+    }
+
+    /// Sets whether all the axes loop
+    pub fn looping_all(mut self, looping: bool) -> Self {
+        self.looping = (looping, looping, looping);
+        self
+    }
+
+    /// Makes the X axis loop
+    pub fn loop_x(self) -> Self {
+        self.looping_x(true)
+    }
+
+    /// Makes the Y axis loop
+    pub fn loop_y(self) -> Self {
+        self.looping_y(true)
+    }
+
+    /// Makes the Z axis loop
+    pub fn loop_z(self) -> Self {
+        self.looping_z(true)
+    }
+
+    /// Builds the [`CartesianGrid`].
+    ///
+    /// Returns [`GridError::ZeroDimension`] if any size is 0 and [`GridError::DimensionOverflow`] if the total size of the grid does not fit in the grid index type.
+    pub fn try_build(self) -> Result<CartesianGrid<C>, GridError> {
+        let (size_x, size_y, size_z) = self.size;
+        let (looping_x, looping_y, looping_z) = self.looping;
+        CartesianGrid::try_new(
+            size_x,
+            size_y,
+            size_z,
+            looping_x,
+            looping_y,
+            looping_z,
+            self.coord_system,
+        )
+    }
+
+    /// Builds the [`CartesianGrid`].
+    ///
+    /// Panics if the sizes are not valid, see [`CartesianGridBuilder::try_build`].
+    pub fn build(self) -> CartesianGrid<C> {
+        let (size_x, size_y, size_z) = self.size;
+        let (looping_x, looping_y, looping_z) = self.looping;
+        CartesianGrid::new(
+            size_x,
+            size_y,
+            size_z,
+            looping_x,
+            looping_y,
+            looping_z,
+            self.coord_system,
+        )
+    }
 }
 
 impl<C: CartesianCoordinates> NodeRef<C, CartesianGrid<C>> for CartesianPosition {
@@ -355,4 +1405,49 @@ impl<C: CartesianCoordinates> NodeRef<C, CartesianGrid<C>> for CartesianPosition
     fn to_index(&self, grid: &CartesianGrid<C>) -> GridIndex {
         grid.index_from_pos(self)
     }
+
+    #[inline]
+    fn try_to_index(&self, grid: &CartesianGrid<C>) -> Result<GridIndex, GridError> {
+        grid.check_coords(self.x, self.y, self.z)?;
+        Ok(grid.index_from_pos(self))
+    }
+}
+
+impl<C: CartesianCoordinates> NodeRef<C, CartesianGrid<C>> for (u32, u32) {
+    #[inline]
+    fn to_index(&self, grid: &CartesianGrid<C>) -> GridIndex {
+        grid.index_from_coords(self.0, self.1, 0)
+    }
+
+    #[inline]
+    fn try_to_index(&self, grid: &CartesianGrid<C>) -> Result<GridIndex, GridError> {
+        grid.check_coords(self.0, self.1, 0)?;
+        Ok(grid.index_from_coords(self.0, self.1, 0))
+    }
+}
+
+impl<C: CartesianCoordinates> NodeRef<C, CartesianGrid<C>> for (u32, u32, u32) {
+    #[inline]
+    fn to_index(&self, grid: &CartesianGrid<C>) -> GridIndex {
+        grid.index_from_coords(self.0, self.1, self.2)
+    }
+
+    #[inline]
+    fn try_to_index(&self, grid: &CartesianGrid<C>) -> Result<GridIndex, GridError> {
+        grid.check_coords(self.0, self.1, self.2)?;
+        Ok(grid.index_from_coords(self.0, self.1, self.2))
+    }
+}
+
+impl NodeRef<Cartesian2D, CartesianGrid<Cartesian2D>> for CartesianPosition2D {
+    #[inline]
+    fn to_index(&self, grid: &CartesianGrid<Cartesian2D>) -> GridIndex {
+        grid.get_index_2d(self.x, self.y)
+    }
+
+    #[inline]
+    fn try_to_index(&self, grid: &CartesianGrid<Cartesian2D>) -> Result<GridIndex, GridError> {
+        grid.check_coords(self.x, self.y, 0)?;
+        Ok(grid.get_index_2d(self.x, self.y))
+    }
 }
@@ -1,19 +1,38 @@
-use std::collections::VecDeque;
+use alloc::{collections::VecDeque, string::String, vec::Vec};
+use core::{
+    fmt::Write,
+    ops::{Index, IndexMut},
+};
 
-use crate::{direction::Direction, grid::GridData};
+use crate::{
+    direction::{Axis, Direction, DirectionTrait, MAX_DIRECTIONS},
+    error::GridError,
+    grid::{bit_grid_data::BitGridData, grid_data_ref::GridDataRef, Grid, GridData, GridIndex},
+};
 
 use super::{
-    coordinates::{Cartesian2D, Cartesian3D, CartesianCoordinates, CartesianPosition},
+    axis_swapped::AxisOrder,
+    coordinates::{Cartesian2D, Cartesian3D, CartesianCoordinates, CartesianPosition, GridDelta},
     grid::CartesianGrid,
+    region::GridRegion,
 };
 
+/// Describes how the elements of a grid moved during a [`GridData::resize`].
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResizeSummary {
+    /// `(old index, new index)` of each element whose position is valid in both the old and the new grid, in increasing new index order
+    pub remap: Vec<(GridIndex, GridIndex)>,
+    /// New indexes of the elements only present in the new grid, set to the fill value, in increasing order
+    pub filled: Vec<GridIndex>,
+}
+
 impl<C: CartesianCoordinates, D> GridData<C, D, CartesianGrid<C>> {
     /// Returns a reference to the element at this position.
     ///
     /// NO CHECK is done to verify that the given position is a valid position for this grid.
     #[inline]
     pub fn get_from_pos(&self, pos: &CartesianPosition) -> &D {
-        &self.get(self.grid().index_from_pos(pos))
+        self.get(self.grid().index_from_pos(pos))
     }
 
     /// Returns a reference to the element at this position.
@@ -24,6 +43,418 @@ impl<C: CartesianCoordinates, D> GridData<C, D, CartesianGrid<C>> {
         self.get_mut(self.grid().index_from_pos(pos))
     }
 
+    /// Calls `f` with a mutable reference to the element at this position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn apply_at_pos<F: FnOnce(&mut D)>(&mut self, pos: &CartesianPosition, f: F) {
+        f(self.get_mut_from_pos(pos));
+    }
+
+    /// Returns an iterator over `count` elements, starting at index `start` and separated by `stride`
+    #[inline]
+    fn iter_strided(&self, start: GridIndex, stride: u32, count: u32) -> impl Iterator<Item = &D> {
+        self.as_slice()[start as usize..]
+            .iter()
+            .step_by(stride as usize)
+            .take(count as usize)
+    }
+
+    /// Swaps the values of the elements at positions `a` and `b`.
+    ///
+    /// NO CHECK is done to verify that the given positions are valid positions for this grid.
+    #[inline]
+    pub fn swap_pos(&mut self, a: &CartesianPosition, b: &CartesianPosition) {
+        let (index_a, index_b) = (self.grid().index_from_pos(a), self.grid().index_from_pos(b));
+        self.swap(index_a, index_b);
+    }
+
+    /// Returns a copy of the grid content, transformed by `f`, in row-major order: x varies fastest, then y, then z.
+    ///
+    /// Index `i` of the returned `Vec` corresponds to the position `pos_from_index(i)`.
+    pub fn to_row_major<E, F: FnMut(&D) -> E>(&self, mut f: F) -> Vec<E> {
+        let grid = self.grid();
+        let mut row_major = Vec::with_capacity(grid.total_size());
+        for z in 0..grid.size_z() {
+            for y in 0..grid.size_y() {
+                for x in 0..grid.size_x() {
+                    row_major.push(f(self.get(grid.index_from_coords(x, y, z))));
+                }
+            }
+        }
+        row_major
+    }
+
+    /// Returns the positions of all the elements for which `pred` returns `true`, in index order.
+    pub fn positions_where<F: Fn(&D) -> bool>(&self, pred: F) -> Vec<CartesianPosition> {
+        self.indices_where(pred)
+            .into_iter()
+            .map(|index| self.grid().pos_from_index(index))
+            .collect()
+    }
+
+    /// Same as [`GridData::max_by_key`] but returns the position of the element instead of its index.
+    pub fn max_by_key_pos<B: Ord, F: Fn(&D) -> B>(&self, f: F) -> Option<(CartesianPosition, &D)> {
+        self.max_by_key(f)
+            .map(|(index, d)| (self.grid().pos_from_index(index), d))
+    }
+
+    /// Same as [`GridData::min_by_key`] but returns the position of the element instead of its index.
+    pub fn min_by_key_pos<B: Ord, F: Fn(&D) -> B>(&self, f: F) -> Option<(CartesianPosition, &D)> {
+        self.min_by_key(f)
+            .map(|(index, d)| (self.grid().pos_from_index(index), d))
+    }
+
+    /// Returns the positions of the elements for which `pred` returns `true` and the positions of the other elements, both in index order, in a single pass over the grid.
+    pub fn partition_positions<F: Fn(&D) -> bool>(
+        &self,
+        pred: F,
+    ) -> (Vec<CartesianPosition>, Vec<CartesianPosition>) {
+        let mut matching = Vec::new();
+        let mut others = Vec::new();
+        for (pos, element) in self.grid().position_walker().zip(self.iter()) {
+            match pred(element) {
+                true => matching.push(pos),
+                false => others.push(pos),
+            }
+        }
+        (matching, others)
+    }
+
+    /// Returns `true` if this grid and `other` have the same size on each axis and equal data. Unlike [`PartialEq`], looping flags are ignored.
+    pub fn content_eq(&self, other: &GridData<C, D, CartesianGrid<C>>) -> bool
+    where
+        D: PartialEq,
+    {
+        self.grid().size() == other.grid().size() && self.as_slice() == other.as_slice()
+    }
+
+    /// Returns the positions of the elements which differ between this grid and `other`, with their value in this grid, in index order.
+    ///
+    /// Returns [`GridError::DimensionMismatch`] if the grids do not have the same total size, or [`GridError::GridMismatch`] if the grids are not equal.
+    pub fn diff(
+        &self,
+        other: &GridData<C, D, CartesianGrid<C>>,
+    ) -> Result<Vec<(CartesianPosition, &D)>, GridError>
+    where
+        D: PartialEq,
+        C: PartialEq,
+    {
+        Ok(self
+            .diff_indices(other)?
+            .into_iter()
+            .map(|index| (self.grid().pos_from_index(index), self.get(index)))
+            .collect())
+    }
+
+    /// Writes each `(position, value)` of `changes` in this grid, such as the changes returned by [`GridData::diff`].
+    ///
+    /// Returns [`GridError::PositionOutOfBounds`] if any position is not in the grid, in which case no change is applied.
+    pub fn apply_changes<I: IntoIterator<Item = (CartesianPosition, D)>>(
+        &mut self,
+        changes: I,
+    ) -> Result<(), GridError> {
+        let changes: Vec<_> = changes.into_iter().collect();
+        for (pos, _) in changes.iter() {
+            self.grid().check_coords(pos.x, pos.y, pos.z)?;
+        }
+        self.apply_changes_unchecked(changes);
+        Ok(())
+    }
+
+    /// Same as [`GridData::apply_changes`] without validating the positions.
+    ///
+    /// NO CHECK is done to verify that the given positions are valid positions for this grid.
+    pub fn apply_changes_unchecked<I: IntoIterator<Item = (CartesianPosition, D)>>(
+        &mut self,
+        changes: I,
+    ) {
+        for (pos, value) in changes {
+            *self.get_mut_from_pos(&pos) = value;
+        }
+    }
+
+    /// Returns an iterator over the data of all the in-bounds neighbours of the element at `index`, with the [`Direction`] leading to them.
+    ///
+    /// NO CHECK is done to verify that the given `index` is a valid index for this grid.
+    pub fn neighbour_values(&self, index: GridIndex) -> impl Iterator<Item = (Direction, &D)> {
+        let grid = self.grid();
+        grid.coord_system()
+            .directions()
+            .iter()
+            .filter_map(move |&direction| {
+                grid.neighbour(index, direction)
+                    .map(|neighbour| (direction, self.get(neighbour)))
+            })
+    }
+
+    /// Returns the number of in-bounds neighbours of the element at `index` whose data satisfies `predicate`.
+    ///
+    /// NO CHECK is done to verify that the given `index` is a valid index for this grid.
+    pub fn count_neighbours_where<F: Fn(&D) -> bool>(
+        &self,
+        index: GridIndex,
+        predicate: F,
+    ) -> usize {
+        self.neighbour_values(index)
+            .filter(|(_, d)| predicate(d))
+            .count()
+    }
+
+    /// Folds `f` over the data of all the in-bounds neighbours of the element at `index`, starting from `init`, and returns the final value.
+    ///
+    /// Neighbours are visited in the order of the directions of the coordinate system. Looping neighbours are included.
+    ///
+    /// NO CHECK is done to verify that the given `index` is a valid index for this grid.
+    pub fn fold_neighbours<B, F: FnMut(B, Direction, &D) -> B>(
+        &self,
+        index: GridIndex,
+        init: B,
+        mut f: F,
+    ) -> B {
+        self.neighbour_values(index)
+            .fold(init, |acc, (direction, d)| f(acc, direction, d))
+    }
+
+    /// Same as [`GridData::flood_fill`] but expands from each node in the given `directions` only, in this order of priority, instead of the fixed vertical/horizontal scheme.
+    ///
+    /// Nodes are processed in the order they are reached, so directions listed first spread first. Works on 3d grids too.
+    ///
+    /// /!\ This uses 'conditon'+'action' as a way to not backtrack. If the effect of 'action' does not disables 'condition', this will loop !
+    pub fn flood_fill_ordered<CO: FnMut(&D) -> bool, AC: FnMut(&mut D)>(
+        &mut self,
+        from: impl Into<CartesianPosition>,
+        directions: &[Direction],
+        mut condition: CO,
+        mut action: AC,
+        pre_allocated_queue: Option<&mut VecDeque<CartesianPosition>>,
+    ) {
+        let queue = match pre_allocated_queue {
+            Some(q) => {
+                q.clear();
+                q
+            }
+            None => &mut VecDeque::with_capacity(10),
+        };
+
+        let initial_pos = from.into();
+        let initial_node = self.get_mut_from_pos(&initial_pos);
+        if !condition(initial_node) {
+            return;
+        }
+        action(initial_node);
+        queue.push_back(initial_pos);
+
+        while let Some(pos) = queue.pop_front() {
+            for &direction in directions {
+                if let Some(next_pos) = self.grid().get_next_pos_in_direction(&pos, direction) {
+                    let node_data = self.get_mut_from_pos(&next_pos);
+                    if condition(node_data) {
+                        action(node_data);
+                        queue.push_back(next_pos);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Partitions the grid into non-overlapping chunks of size `chunk_size` and returns an iterator over them, by increasing z, then y, then x.
+    ///
+    /// Each chunk is returned as its region and an iterator over its positions and elements. When the size of the grid is not a multiple of `chunk_size` on an axis, the last chunks on this axis are smaller.
+    ///
+    /// Panics if any component of `chunk_size` is 0.
+    pub fn chunks(
+        &self,
+        chunk_size: (u32, u32, u32),
+    ) -> impl Iterator<Item = (GridRegion, impl Iterator<Item = (CartesianPosition, &D)>)> {
+        assert!(
+            chunk_size.0 > 0 && chunk_size.1 > 0 && chunk_size.2 > 0,
+            "chunk size must not be 0"
+        );
+        let grid = self.grid();
+        let (size_x, size_y, size_z) = grid.size();
+        (0..size_z)
+            .step_by(chunk_size.2 as usize)
+            .flat_map(move |z| {
+                (0..size_y)
+                    .step_by(chunk_size.1 as usize)
+                    .flat_map(move |y| {
+                        (0..size_x)
+                            .step_by(chunk_size.0 as usize)
+                            .map(move |x| CartesianPosition::new(x, y, z))
+                    })
+            })
+            .map(move |min| {
+                let region = GridRegion::from_size(min, chunk_size).clamped_to(grid);
+                (region, self.iter_region(region))
+            })
+    }
+
+    /// Returns an iterator over the positions and elements of the grid contained in `region`, by increasing z, then y, then x.
+    ///
+    /// The region is clamped to the grid, positions outside of the grid are skipped.
+    pub fn iter_region(&self, region: GridRegion) -> impl Iterator<Item = (CartesianPosition, &D)> {
+        let region = region.clamped_to(self.grid());
+        let size_y = self.grid().size_y();
+        self.rows()
+            .enumerate()
+            .filter_map(move |(row, elements)| {
+                region_row(&region, row, size_y).map(|(y, z)| {
+                    elements[region.min.x as usize..region.max.x as usize]
+                        .iter()
+                        .zip(region.min.x..)
+                        .map(move |(element, x)| (CartesianPosition::new(x, y, z), element))
+                })
+            })
+            .flatten()
+    }
+
+    /// Returns an iterator over the positions and mutable elements of the grid contained in `region`, by increasing z, then y, then x.
+    ///
+    /// The region is clamped to the grid, positions outside of the grid are skipped.
+    pub fn iter_region_mut(
+        &mut self,
+        region: GridRegion,
+    ) -> impl Iterator<Item = (CartesianPosition, &mut D)> {
+        let region = region.clamped_to(self.grid());
+        let size_y = self.grid().size_y();
+        self.rows_mut()
+            .enumerate()
+            .filter_map(move |(row, elements)| {
+                region_row(&region, row, size_y).map(|(y, z)| {
+                    elements[region.min.x as usize..region.max.x as usize]
+                        .iter_mut()
+                        .zip(region.min.x..)
+                        .map(move |(element, x)| (CartesianPosition::new(x, y, z), element))
+                })
+            })
+            .flatten()
+    }
+
+    /// Sets the elements of the grid contained in `region` to the values given by `values`, by increasing z, then y, then x.
+    ///
+    /// The region is clamped to the grid. Stops when either all the elements of the region are set or `values` is exhausted.
+    pub fn set_region_from_iter<I: IntoIterator<Item = D>>(
+        &mut self,
+        region: GridRegion,
+        values: I,
+    ) {
+        for ((_, element), value) in self.iter_region_mut(region).zip(values) {
+            *element = value;
+        }
+    }
+
+    /// Calls `f` with the position and a mutable reference of each element of the grid contained in `region`, by increasing z, then y, then x.
+    ///
+    /// The region is clamped to the grid, positions outside of the grid are skipped. See [`GridData::iter_region_mut`].
+    pub fn for_each_in_region_mut<F: FnMut(CartesianPosition, &mut D)>(
+        &mut self,
+        region: GridRegion,
+        mut f: F,
+    ) {
+        for (pos, element) in self.iter_region_mut(region) {
+            f(pos, element);
+        }
+    }
+
+    /// Returns an iterator over the positions of all the elements and mutable references to them, in index order.
+    ///
+    /// Positions are computed incrementally, row by row.
+    pub fn iter_mut_positioned(&mut self) -> impl Iterator<Item = (CartesianPosition, &mut D)> {
+        let size_y = self.grid().size_y() as usize;
+        self.rows_mut()
+            .enumerate()
+            .flat_map(move |(row, elements)| {
+                let (y, z) = ((row % size_y) as u32, (row / size_y) as u32);
+                elements
+                    .iter_mut()
+                    .zip(0..)
+                    .map(move |(element, x)| (CartesianPosition::new(x, y, z), element))
+            })
+    }
+
+    /// Returns an iterator over the positions of all the elements, references to them and to the values of their neighbours, in index order.
+    ///
+    /// Neighbour values are indexed by direction, with `None` for the neighbours outside of the grid (looping is respected) and for the directions that are not in the coordinate system.
+    pub fn iter_with_neighbours(
+        &self,
+    ) -> impl Iterator<Item = (CartesianPosition, &D, [Option<&D>; MAX_DIRECTIONS])> {
+        let grid = self.grid();
+        grid.position_walker()
+            .zip(self.iter())
+            .map(move |(pos, element)| {
+                let mut neighbours = [None; MAX_DIRECTIONS];
+                for direction in grid.coord_system().directions() {
+                    neighbours[*direction as usize] = grid
+                        .get_next_index_in_direction(&pos, *direction)
+                        .map(|index| self.get(index));
+                }
+                (pos, element, neighbours)
+            })
+    }
+
+    /// Returns an iterator over the rows of the grid, as contiguous slices of `size_x` elements, by increasing z, then y.
+    pub fn rows(&self) -> impl Iterator<Item = &[D]> {
+        self.as_slice().chunks_exact(self.grid().size_x() as usize)
+    }
+
+    /// Returns an iterator over the rows of the grid, as contiguous mutable slices of `size_x` elements, by increasing z, then y.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [D]> {
+        let size_x = self.grid().size_x() as usize;
+        self.as_mut_slice().chunks_exact_mut(size_x)
+    }
+
+    /// Returns an iterator over the layers of the grid, as contiguous slices of `size_xy` elements, by increasing z.
+    pub fn layers(&self) -> impl Iterator<Item = &[D]> {
+        self.as_slice().chunks_exact(self.grid().size_xy() as usize)
+    }
+
+    /// Returns an iterator over the layers of the grid, as contiguous mutable slices of `size_xy` elements, by increasing z.
+    pub fn layers_mut(&mut self) -> impl Iterator<Item = &mut [D]> {
+        let size_xy = self.grid().size_xy() as usize;
+        self.as_mut_slice().chunks_exact_mut(size_xy)
+    }
+
+    /// Mirrors the grid content along the X axis, in place: the element at (`x`, `y`, `z`) moves to (`size_x - 1 - x`, `y`, `z`).
+    ///
+    /// Elements are swapped pairwise, without any allocation.
+    pub fn mirror_x_in_place(&mut self) {
+        for row in self.rows_mut() {
+            row.reverse();
+        }
+    }
+
+    /// Mirrors the grid content along the Y axis, in place: the element at (`x`, `y`, `z`) moves to (`x`, `size_y - 1 - y`, `z`).
+    ///
+    /// Elements are swapped pairwise, without any allocation.
+    pub fn mirror_y_in_place(&mut self) {
+        let (size_x, size_y, _) = self.grid().size();
+        let (size_x, size_y) = (size_x as usize, size_y as usize);
+        for layer in self.layers_mut() {
+            for y in 0..size_y / 2 {
+                let (low, high) = (y * size_x, (size_y - 1 - y) * size_x);
+                for x in 0..size_x {
+                    layer.swap(low + x, high + x);
+                }
+            }
+        }
+    }
+
+    /// Mirrors the grid content along the Z axis, in place: the element at (`x`, `y`, `z`) moves to (`x`, `y`, `size_z - 1 - z`).
+    ///
+    /// Elements are swapped pairwise, without any allocation.
+    pub fn mirror_z_in_place(&mut self) {
+        let size_xy = self.grid().size_xy() as usize;
+        let size_z = self.grid().size_z() as usize;
+        let data = self.as_mut_slice();
+        for z in 0..size_z / 2 {
+            let (low, high) = (z * size_xy, (size_z - 1 - z) * size_xy);
+            for i in 0..size_xy {
+                data.swap(low + i, high + i);
+            }
+        }
+    }
+
     /// Returns the data at the next position in the grid when moving 1 unit in `direction` from `grid_position`.
     ///
     /// Returns `None` if the destination is not in the grid.
@@ -36,7 +467,61 @@ impl<C: CartesianCoordinates, D> GridData<C, D, CartesianGrid<C>> {
     ) -> Option<&D> {
         self.grid()
             .get_next_index_in_direction(grid_position, direction)
-            .and_then(|index| Some(self.get(index)))
+            .map(|index| self.get(index))
+    }
+}
+
+impl<C: CartesianCoordinates> BitGridData<C, CartesianGrid<C>> {
+    /// Returns the value of the element at this position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn get_from_pos(&self, pos: &CartesianPosition) -> bool {
+        self.get(self.grid().index_from_pos(pos))
+    }
+
+    /// Sets the value of the element at this position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn set_from_pos(&mut self, pos: &CartesianPosition, value: bool) {
+        self.set_raw(self.grid().index_from_pos(pos), value);
+    }
+}
+
+impl<C: CartesianCoordinates, D> GridDataRef<'_, C, D, CartesianGrid<C>> {
+    /// Returns a reference to the element at this position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn get_from_pos(&self, pos: &CartesianPosition) -> &D {
+        self.get(self.grid().index_from_pos(pos))
+    }
+
+    /// Returns a mutable reference to the element at this position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn get_mut_from_pos(&mut self, pos: &CartesianPosition) -> &mut D {
+        self.get_mut(self.grid().index_from_pos(pos))
+    }
+}
+
+/// Same as [`GridData::get_from_pos`]: NO CHECK is done to verify that the given position is a valid position for this grid.
+impl<C: CartesianCoordinates, D> Index<CartesianPosition> for GridData<C, D, CartesianGrid<C>> {
+    type Output = D;
+
+    #[inline]
+    fn index(&self, pos: CartesianPosition) -> &D {
+        self.get_from_pos(&pos)
+    }
+}
+
+/// Same as [`GridData::get_mut_from_pos`]: NO CHECK is done to verify that the given position is a valid position for this grid.
+impl<C: CartesianCoordinates, D> IndexMut<CartesianPosition> for GridData<C, D, CartesianGrid<C>> {
+    #[inline]
+    fn index_mut(&mut self, pos: CartesianPosition) -> &mut D {
+        self.get_mut_from_pos(&pos)
     }
 }
 
@@ -47,7 +532,7 @@ impl<C: CartesianCoordinates, D: Clone> GridData<C, D, CartesianGrid<C>> {
         let mut index = x;
         for _z in 0..self.grid().size_z() {
             for _y in 0..self.grid().size_y() {
-                self.set_raw(index as usize, value.clone());
+                self.set_raw(index as GridIndex, value.clone());
                 index += self.grid().size_x();
             }
         }
@@ -58,7 +543,7 @@ impl<C: CartesianCoordinates, D: Clone> GridData<C, D, CartesianGrid<C>> {
         let mut index = y * self.grid().size_x();
         for _z in 0..self.grid().size_z() {
             for _x in 0..self.grid().size_x() {
-                self.set_raw(index as usize, value.clone());
+                self.set_raw(index as GridIndex, value.clone());
                 index += 1;
             }
             index += self.grid().size_xy() - self.grid().size_x();
@@ -69,7 +554,7 @@ impl<C: CartesianCoordinates, D: Clone> GridData<C, D, CartesianGrid<C>> {
         let mut index = z * self.grid().size_xy();
         for _y in 0..self.grid().size_y() {
             for _x in 0..self.grid().size_x() {
-                self.set_raw(index as usize, value.clone());
+                self.set_raw(index as GridIndex, value.clone());
                 index += 1;
             }
         }
@@ -79,7 +564,7 @@ impl<C: CartesianCoordinates, D: Clone> GridData<C, D, CartesianGrid<C>> {
     pub fn set_all_xy(&mut self, x: u32, y: u32, value: D) {
         let mut index = x + y * self.grid().size_x();
         for _z in 0..self.grid().size_z() {
-            self.set_raw(index as usize, value.clone());
+            self.set_raw(index as GridIndex, value.clone());
             index += self.grid().size_xy();
         }
     }
@@ -88,28 +573,388 @@ impl<C: CartesianCoordinates, D: Clone> GridData<C, D, CartesianGrid<C>> {
     pub fn set_all_xz(&mut self, x: u32, z: u32, value: D) {
         let mut index = x + z * self.grid().size_xy();
         for _y in 0..self.grid().size_y() {
-            self.set_raw(index as usize, value.clone());
+            self.set_raw(index as GridIndex, value.clone());
             index += self.grid().size_x();
         }
     }
 
     /// Sets all nodes of the grix with y=`y` and z=`z` to `value`
     pub fn set_all_yz(&mut self, y: u32, z: u32, value: D) {
-        let mut index = y * self.grid().size_x() + z * self.grid().size_xy();
-        for _x in 0..self.grid().size_x() {
-            self.set_raw(index as usize, value.clone());
-            index += 1;
+        let start = y * self.grid().size_x() + z * self.grid().size_xy();
+        for index in start..start + self.grid().size_x() {
+            self.set_raw(index as GridIndex, value.clone());
+        }
+    }
+
+    /// Moves the value of every element by `delta`.
+    ///
+    /// On looping axes, values wrap around. On non-looping axes, values moved outside of the grid are dropped and elements left without a value are set to `fill`. Each axis is handled independently, so a diagonal `delta` can wrap on one axis and drop values on another.
+    pub fn shift(&mut self, delta: GridDelta, fill: D) {
+        let source_delta = delta * -1;
+        let (grid, data) = self.grid_and_data_mut();
+        let shifted = grid
+            .indexes()
+            .map(|index| match grid.wrap_index(index, &source_delta) {
+                Some(source) => data[source as usize].clone(),
+                None => fill.clone(),
+            })
+            .collect();
+        *data = shifted;
+    }
+
+    /// Sets each element matching `predicate` to `reset`, and returns the positions of these elements in index order.
+    pub fn take_where<F: Fn(&D) -> bool>(
+        &mut self,
+        predicate: F,
+        reset: D,
+    ) -> Vec<CartesianPosition> {
+        let (grid, data) = self.grid_and_data_mut();
+        let mut taken = Vec::new();
+        for (index, d) in data.iter_mut().enumerate() {
+            if predicate(d) {
+                *d = reset.clone();
+                taken.push(grid.pos_from_index(index as GridIndex));
+            }
+        }
+        taken
+    }
+
+    /// Resizes the grid to `new_size`, and returns which elements were kept and which were filled, see [`ResizeSummary`].
+    ///
+    /// Elements whose position is valid in both the old and the new grid keep their value, elements only present in the new grid are set to `fill`, and elements only present in the old grid are dropped.
+    ///
+    /// When the grid grows or shrinks on all axes, the existing data buffer is reused.
+    ///
+    /// Returns [`GridError::ZeroDimension`] if any size is 0 and [`GridError::DimensionOverflow`] if the new grid would be too big, see [`CartesianGrid::try_new`]. The grid and its data are left unchanged on error.
+    pub fn resize(
+        &mut self,
+        new_size: (u32, u32, u32),
+        fill: D,
+    ) -> Result<ResizeSummary, GridError> {
+        let (grid, data) = self.grid_and_data_mut();
+        let old_grid = grid.clone();
+        let old_size = old_grid.size();
+        grid.set_size(new_size.0, new_size.1, new_size.2)?;
+        let kept = (
+            old_size.0.min(new_size.0),
+            old_size.1.min(new_size.1),
+            old_size.2.min(new_size.2),
+        );
+        let in_old = |x, y, z| x < old_size.0 && y < old_size.1 && z < old_size.2;
+        let kept_positions = (0..kept.2)
+            .flat_map(move |z| (0..kept.1).flat_map(move |y| (0..kept.0).map(move |x| (x, y, z))));
+        let summary = ResizeSummary {
+            remap: kept_positions
+                .clone()
+                .map(|(x, y, z)| {
+                    (
+                        old_grid.index_from_coords(x, y, z),
+                        grid.index_from_coords(x, y, z),
+                    )
+                })
+                .collect(),
+            filled: grid
+                .indexes()
+                .filter(|index| {
+                    let pos = grid.pos_from_index(*index);
+                    !in_old(pos.x, pos.y, pos.z)
+                })
+                .collect(),
+        };
+
+        if new_size.0 >= old_size.0 && new_size.1 >= old_size.1 && new_size.2 >= old_size.2 {
+            // Kept elements only move towards the end of the buffer: move them starting from the last one.
+            data.resize(grid.total_size(), fill.clone());
+            for (old_index, new_index) in summary.remap.iter().rev() {
+                data.swap(*old_index as usize, *new_index as usize);
+            }
+            for index in summary.filled.iter() {
+                data[*index as usize] = fill.clone();
+            }
+        } else if new_size.0 <= old_size.0 && new_size.1 <= old_size.1 && new_size.2 <= old_size.2 {
+            // Kept elements only move towards the start of the buffer: move them starting from the first one.
+            for (old_index, new_index) in summary.remap.iter() {
+                data.swap(*old_index as usize, *new_index as usize);
+            }
+            data.truncate(grid.total_size());
+        } else {
+            let mut new_data = Vec::with_capacity(grid.total_size());
+            for z in 0..new_size.2 {
+                for y in 0..new_size.1 {
+                    for x in 0..new_size.0 {
+                        if in_old(x, y, z) {
+                            new_data
+                                .push(data[old_grid.index_from_coords(x, y, z) as usize].clone());
+                        } else {
+                            new_data.push(fill.clone());
+                        }
+                    }
+                }
+            }
+            *data = new_data;
+        }
+        Ok(summary)
+    }
+
+    /// Copies the content of `other` into this grid, with `other`'s origin placed at `at`.
+    ///
+    /// Elements of `other` that would fall outside of this grid are ignored.
+    pub fn paste(&mut self, other: &GridData<C, D, CartesianGrid<C>>, at: CartesianPosition) {
+        self.paste_with(other, at, |_, source| source.clone());
+    }
+
+    /// Same as [`GridData::paste`] but each element of this grid covered by `other` is set to the result of `merge`, called with the current value of the element in this grid and the value of the element in `other`.
+    pub fn paste_with<F: Fn(&D, &D) -> D>(
+        &mut self,
+        other: &GridData<C, D, CartesianGrid<C>>,
+        at: CartesianPosition,
+        merge: F,
+    ) {
+        let other_grid = other.grid();
+        let size_x = other_grid
+            .size_x()
+            .min(self.grid().size_x().saturating_sub(at.x));
+        let size_y = other_grid
+            .size_y()
+            .min(self.grid().size_y().saturating_sub(at.y));
+        let size_z = other_grid
+            .size_z()
+            .min(self.grid().size_z().saturating_sub(at.z));
+        for z in 0..size_z {
+            for y in 0..size_y {
+                for x in 0..size_x {
+                    let index = self.grid().index_from_coords(at.x + x, at.y + y, at.z + z);
+                    let value = merge(
+                        self.get(index),
+                        other.get(other_grid.index_from_coords(x, y, z)),
+                    );
+                    self.set_raw(index, value);
+                }
+            }
         }
     }
+
+    /// Sets all the elements of this grid inside `region` to `value`.
+    ///
+    /// The region is clamped to the bounds of this grid.
+    pub fn fill_region(&mut self, region: &GridRegion, value: D) {
+        for pos in region.clamped_to(self.grid()).iter_positions() {
+            let index = self.grid().index_from_pos(&pos);
+            self.set_raw(index, value.clone());
+        }
+    }
+
+    /// Returns a copy of this grid where the axis k of the copy is the axis `axes[k]` of this grid, with sizes, looping flags and content permuted accordingly
+    fn permuted(&self, axes: [Axis; 3]) -> GridData<C, D, CartesianGrid<C>> {
+        let grid = self.grid();
+        let permuted_grid = CartesianGrid::new(
+            grid.axis_size(axes[0]),
+            grid.axis_size(axes[1]),
+            grid.axis_size(axes[2]),
+            grid.axis_looping(axes[0]),
+            grid.axis_looping(axes[1]),
+            grid.axis_looping(axes[2]),
+            grid.coord_system().clone(),
+        );
+        let data = permuted_grid
+            .indexes()
+            .map(|index| {
+                let permuted_pos = permuted_grid.pos_from_index(index).to_array();
+                let mut coords = [0; 3];
+                for (axis, coord) in axes.iter().zip(permuted_pos) {
+                    coords[*axis as usize] = coord;
+                }
+                self.get_from_pos(&CartesianPosition::from_array(coords))
+                    .clone()
+            })
+            .collect();
+        GridData::new(permuted_grid, data)
+    }
+
+    /// Returns a copy of this grid scaled up by the given `(x, y, z)` factors, where each element is replicated into a block of `factors` elements. Looping flags are kept.
+    ///
+    /// A factor of 1 leaves its axis unchanged. Returns [`GridError::ZeroDimension`] if a factor is 0 and [`GridError::DimensionOverflow`] if the scaled grid is too big.
+    pub fn scale_up(
+        &self,
+        factors: (u32, u32, u32),
+    ) -> Result<GridData<C, D, CartesianGrid<C>>, GridError> {
+        let grid = self.grid();
+        let (size_x, size_y, size_z) = grid.size();
+        let (factor_x, factor_y, factor_z) = factors;
+        let overflow = GridError::DimensionOverflow {
+            size: (
+                size_x.saturating_mul(factor_x),
+                size_y.saturating_mul(factor_y),
+                size_z.saturating_mul(factor_z),
+            ),
+        };
+        let (looping_x, looping_y, looping_z) = grid.looping();
+        let scaled_grid = CartesianGrid::try_new(
+            size_x.checked_mul(factor_x).ok_or(overflow.clone())?,
+            size_y.checked_mul(factor_y).ok_or(overflow.clone())?,
+            size_z.checked_mul(factor_z).ok_or(overflow)?,
+            looping_x,
+            looping_y,
+            looping_z,
+            grid.coord_system().clone(),
+        )?;
+        let data = scaled_grid
+            .indexes()
+            .map(|index| {
+                let pos = scaled_grid.pos_from_index(index);
+                let source =
+                    CartesianPosition::new(pos.x / factor_x, pos.y / factor_y, pos.z / factor_z);
+                self.get_from_pos(&source).clone()
+            })
+            .collect();
+        Ok(GridData::new(scaled_grid, data))
+    }
+
+    /// Returns a copy of this grid scaled down by the given `(x, y, z)` factors, where each block of `factors` elements is merged into one element by `merge`. Looping flags are kept.
+    ///
+    /// `merge` is given the elements of a block in index order. When a size is not a multiple of its factor, the blocks on the last row/column/layer are smaller. A factor of 1 leaves its axis unchanged. Returns [`GridError::ZeroDimension`] if a factor is 0.
+    pub fn scale_down<F: Fn(&[&D]) -> D>(
+        &self,
+        factors: (u32, u32, u32),
+        merge: F,
+    ) -> Result<GridData<C, D, CartesianGrid<C>>, GridError> {
+        let grid = self.grid();
+        let (size_x, size_y, size_z) = grid.size();
+        let (factor_x, factor_y, factor_z) = factors;
+        let scaled_size = |size: u32, factor: u32| match factor {
+            0 => 0,
+            _ => size.div_ceil(factor),
+        };
+        let (looping_x, looping_y, looping_z) = grid.looping();
+        let scaled_grid = CartesianGrid::try_new(
+            scaled_size(size_x, factor_x),
+            scaled_size(size_y, factor_y),
+            scaled_size(size_z, factor_z),
+            looping_x,
+            looping_y,
+            looping_z,
+            grid.coord_system().clone(),
+        )?;
+        let mut block = Vec::new();
+        let data = scaled_grid
+            .indexes()
+            .map(|index| {
+                let pos = scaled_grid.pos_from_index(index);
+                let (min_x, min_y, min_z) = (pos.x * factor_x, pos.y * factor_y, pos.z * factor_z);
+                block.clear();
+                for z in min_z..min_z.saturating_add(factor_z).min(size_z) {
+                    for y in min_y..min_y.saturating_add(factor_y).min(size_y) {
+                        for x in min_x..min_x.saturating_add(factor_x).min(size_x) {
+                            block.push(self.get(grid.index_from_coords(x, y, z)));
+                        }
+                    }
+                }
+                merge(&block)
+            })
+            .collect();
+        Ok(GridData::new(scaled_grid, data))
+    }
+
+    /// Sets all the elements within `thickness` of a face of the grid to `value`.
+    ///
+    /// Looping axes have no face on that axis and get no border, as well as axes without any direction in the coordinate system (Z for [`Cartesian2D`]).
+    pub fn fill_border(&mut self, thickness: u32, value: D) {
+        let grid = self.grid();
+        let size = grid.size();
+        let mut slabs = Vec::new();
+        for axis in [Axis::X, Axis::Y, Axis::Z] {
+            if grid.axis_looping(axis) || !grid.uses_axis(axis) {
+                continue;
+            }
+            let axis_size = grid.axis_size(axis);
+            let with_axis = |mut coords: [u32; 3], coord: u32| {
+                coords[axis as usize] = coord;
+                CartesianPosition::from_array(coords)
+            };
+            let full = [size.0, size.1, size.2];
+            slabs.push(GridRegion::new(
+                CartesianPosition::default(),
+                with_axis(full, thickness.min(axis_size)),
+            ));
+            slabs.push(GridRegion::new(
+                with_axis([0; 3], axis_size.saturating_sub(thickness)),
+                CartesianPosition::from_array(full),
+            ));
+        }
+        for slab in slabs {
+            self.fill_region(&slab, value.clone());
+        }
+    }
+
+    /// Returns a copy of the elements inside `region`, as a new non-looping [`GridData`] whose positions start at (0,0,0).
+    ///
+    /// The region is clamped to the bounds of this grid and the returned grid has the clamped size. Returns `None` if the clamped region is empty: when the region itself is empty or is outside of the grid.
+    pub fn subgrid_region(&self, region: &GridRegion) -> Option<GridData<C, D, CartesianGrid<C>>> {
+        self.clamped_subgrid(&region.min, region.size())
+    }
+
+    /// Copies the elements of `other` inside `region` into this grid, with `region.min` placed at `at`.
+    ///
+    /// The region is clamped to the bounds of `other`, and elements that would fall outside of this grid are ignored.
+    pub fn paste_region(
+        &mut self,
+        other: &GridData<C, D, CartesianGrid<C>>,
+        region: &GridRegion,
+        at: CartesianPosition,
+    ) {
+        let target = GridRegion::of_grid(self.grid());
+        for pos in region.clamped_to(other.grid()).iter_positions() {
+            let dest = CartesianPosition::new(
+                at.x.saturating_add(pos.x - region.min.x),
+                at.y.saturating_add(pos.y - region.min.y),
+                at.z.saturating_add(pos.z - region.min.z),
+            );
+            if target.contains(&dest) {
+                let index = self.grid().index_from_pos(&dest);
+                self.set_raw(index, other.get_from_pos(&pos).clone());
+            }
+        }
+    }
+
+    /// Copies the content of the box starting at `min` and of size `size` into a new non-looping [`GridData`].
+    ///
+    /// The box is clamped to the bounds of this grid. Returns `None` if the clamped box is empty: when `size` is 0 on an axis or `min` is past the edge of the grid.
+    fn clamped_subgrid(
+        &self,
+        min: &CartesianPosition,
+        size: (u32, u32, u32),
+    ) -> Option<GridData<C, D, CartesianGrid<C>>> {
+        let grid = self.grid();
+        let subgrid = CartesianGrid::try_new(
+            size.0.min(grid.size_x().saturating_sub(min.x)),
+            size.1.min(grid.size_y().saturating_sub(min.y)),
+            size.2.min(grid.size_z().saturating_sub(min.z)),
+            false,
+            false,
+            false,
+            grid.coord_system().clone(),
+        )
+        .ok()?;
+        Some(subgrid.grid_data_from_fn(|pos| {
+            self.get(grid.index_from_coords(min.x + pos.x, min.y + pos.y, min.z + pos.z))
+                .clone()
+        }))
+    }
 }
 
 impl<D> GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>> {
+    /// Converts this grid data to use a [`Cartesian3D`] coordinate system, moving the data buffer unchanged. See [`CartesianGrid::into_3d`].
+    pub fn reinterpret_3d(self) -> GridData<Cartesian3D, D, CartesianGrid<Cartesian3D>> {
+        let (grid, data) = self.into_raw();
+        GridData::new(grid.into_3d(), data)
+    }
+
     /// Returns a reference to the element at this position.
     ///
     /// NO CHECK is done to verify that the given position is a valid position for this grid.
     #[inline]
     pub fn get_2d(&self, x: u32, y: u32) -> &D {
-        &self.get(self.grid().get_index_2d(x, y))
+        self.get(self.grid().get_index_2d(x, y))
     }
 
     /// Returns a mutable reference to the data at this position.
@@ -120,6 +965,130 @@ impl<D> GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>> {
         self.get_mut(self.grid().get_index_2d(x, y))
     }
 
+    /// Returns an iterator over the elements of the row y=`y`, by increasing x.
+    ///
+    /// NO CHECK is done to verify that `y` is valid for this grid.
+    pub fn iter_row(&self, y: u32) -> impl Iterator<Item = &D> {
+        let size_x = self.grid().size_x();
+        self.iter_strided((y * size_x) as GridIndex, 1, size_x)
+    }
+
+    /// Returns an iterator over the elements of the column x=`x`, by increasing y.
+    ///
+    /// NO CHECK is done to verify that `x` is valid for this grid.
+    pub fn iter_col(&self, x: u32) -> impl Iterator<Item = &D> {
+        let grid = self.grid();
+        self.iter_strided(x as GridIndex, grid.size_x(), grid.size_y())
+    }
+
+    /// Returns an iterator over all the positions of the grid, in index order, with the 3x3 neighbourhood centered on them.
+    ///
+    /// The neighbourhood is indexed as `window[dy + 1][dx + 1]` for offsets `dx` and `dy` in `-1..=1`, so the center element is `window[1][1]` and is always `Some`. Off-grid elements are `None`, or wrapped on looping axes.
+    pub fn windows_3x3(&self) -> impl Iterator<Item = (CartesianPosition, [[Option<&D>; 3]; 3])> {
+        let grid = self.grid();
+        grid.indexes().map(move |index| {
+            let pos = grid.pos_from_index(index);
+            let mut window = [[None; 3]; 3];
+            for (dy, row) in (-1..=1).zip(window.iter_mut()) {
+                for (dx, element) in (-1..=1).zip(row.iter_mut()) {
+                    *element = grid
+                        .get_next_pos(&pos, &GridDelta::new(dx, dy, 0))
+                        .map(|next_pos| self.get_from_pos(&next_pos));
+                }
+            }
+            (pos, window)
+        })
+    }
+
+    /// Returns the positions visited when walking from `from` in `direction`, until reaching an element for which `blocks` returns `true` or the edge of the grid. `from` itself is not included.
+    ///
+    /// The blocking element is included as the last position if `include_blocker` is `true`. On a looping axis, the walk wraps around and stops before coming back to `from`.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    pub fn scan_until<F: Fn(&D) -> bool>(
+        &self,
+        from: CartesianPosition,
+        direction: Direction,
+        blocks: F,
+        include_blocker: bool,
+    ) -> Vec<CartesianPosition> {
+        let grid = self.grid();
+        let max_steps = grid.axis_size(direction.axis()) - 1;
+        let mut visited = Vec::new();
+        let mut pos = from;
+        for _ in 0..max_steps {
+            let Some(next_pos) = grid.get_next_pos_in_direction(&pos, direction) else {
+                break;
+            };
+            if blocks(self.get_from_pos(&next_pos)) {
+                if include_blocker {
+                    visited.push(next_pos);
+                }
+                break;
+            }
+            visited.push(next_pos);
+            pos = next_pos;
+        }
+        visited
+    }
+
+    /// Appends `row` as a new row at y=`size_y`, growing the grid by one on the Y axis. Looping flags are kept.
+    ///
+    /// Returns [`GridError::ShapeMismatch`] if the length of `row` is not the X size of the grid, and [`GridError::DimensionOverflow`] if the grown grid would be too big. The grid is left unchanged on error.
+    pub fn push_row(&mut self, row: Vec<D>) -> Result<(), GridError> {
+        let grid = self.grid();
+        let (size_x, size_y, _) = grid.size();
+        if row.len() != size_x as usize {
+            return Err(GridError::ShapeMismatch {
+                axis: Axis::X,
+                expected: size_x as usize,
+                got: row.len(),
+            });
+        }
+        let (looping_x, looping_y, looping_z) = grid.looping();
+        let new_size_y = size_y.checked_add(1).ok_or(GridError::DimensionOverflow {
+            size: (size_x, u32::MAX, 1),
+        })?;
+        let grown_grid = CartesianGrid::try_new(
+            size_x,
+            new_size_y,
+            1,
+            looping_x,
+            looping_y,
+            looping_z,
+            Cartesian2D,
+        )?;
+        let (grid, data) = self.grid_and_data_mut();
+        *grid = grown_grid;
+        data.extend(row);
+        Ok(())
+    }
+
+    /// Returns a multi-line representation of the grid, each element being converted to a `char` by `f`.
+    ///
+    /// The first line is the row with y=0, y increasing downward. Each line, including the last one, ends with `\n`.
+    pub fn render_ascii<F: Fn(&D) -> char>(&self, f: F) -> String {
+        self.render_ascii_rows(false, f)
+    }
+
+    /// Same as [`GridData::render_ascii`] but with y increasing upward: the first line is the row with the highest y.
+    pub fn render_ascii_y_up<F: Fn(&D) -> char>(&self, f: F) -> String {
+        self.render_ascii_rows(true, f)
+    }
+
+    fn render_ascii_rows<F: Fn(&D) -> char>(&self, y_up: bool, f: F) -> String {
+        let (size_x, size_y) = (self.grid().size_x(), self.grid().size_y());
+        let mut ascii = String::with_capacity((size_x as usize + 1) * size_y as usize);
+        for row in 0..size_y {
+            let y = if y_up { size_y - 1 - row } else { row };
+            for x in 0..size_x {
+                ascii.push(f(self.get_2d(x, y)));
+            }
+            ascii.push('\n');
+        }
+        ascii
+    }
+
     fn explore_vertical<C: FnMut(&D) -> bool, A: FnMut(&mut D)>(
         &mut self,
         queue: &mut VecDeque<CartesianPosition>,
@@ -127,9 +1096,9 @@ impl<D> GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>> {
         condition: &mut C,
         action: &mut A,
     ) {
-        for vertical_dir in vec![Direction::YForward, Direction::YBackward].iter() {
+        for vertical_dir in [Direction::YForward, Direction::YBackward].iter() {
             if let Some(vertical_node_pos) =
-                self.grid().get_next_pos_in_direction(&from, *vertical_dir)
+                self.grid().get_next_pos_in_direction(from, *vertical_dir)
             {
                 let node_data = self.get_mut_from_pos(&vertical_node_pos);
                 if condition(node_data) {
@@ -159,7 +1128,7 @@ impl<D> GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>> {
         pre_allocated_queue: Option<&mut VecDeque<CartesianPosition>>,
     ) {
         // We do not add to the queue if a node is already set. If not set, set and add to queue (to avoid queuing nodes multiple times)
-        let mut queue = match pre_allocated_queue {
+        let queue = match pre_allocated_queue {
             Some(q) => {
                 q.clear();
                 q
@@ -177,9 +1146,9 @@ impl<D> GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>> {
         }
 
         while let Some(pos) = queue.pop_front() {
-            self.explore_vertical(&mut queue, &pos, &mut condition, &mut action);
+            self.explore_vertical(queue, &pos, &mut condition, &mut action);
 
-            for &horizontal_dir in vec![Direction::XBackward, Direction::XForward].iter() {
+            for &horizontal_dir in [Direction::XBackward, Direction::XForward].iter() {
                 let mut x_pos = pos;
 
                 // Use size_x as an upper limit of the iteration count
@@ -193,7 +1162,7 @@ impl<D> GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>> {
                         if condition(node_data) {
                             action(node_data);
                             self.explore_vertical(
-                                &mut queue,
+                                queue,
                                 &next_node_pos,
                                 &mut condition,
                                 &mut action,
@@ -211,13 +1180,83 @@ impl<D> GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>> {
     }
 }
 
+impl<D: Clone> GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>> {
+    /// Returns a copy of the rectangle starting at `min` and of size `size`, as a new non-looping [`GridData`] whose positions start at (0,0).
+    ///
+    /// If the rectangle extends past the bounds of this grid, it is clamped and the returned grid has the clamped size. Returns `None` if the clamped rectangle is empty: when `size` is 0 on an axis or `min` is past the edge of the grid.
+    pub fn subgrid(
+        &self,
+        min: CartesianPosition,
+        size: (u32, u32),
+    ) -> Option<GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>>> {
+        self.clamped_subgrid(&min, (size.0, size.1, 1))
+    }
+
+    /// Returns a transposed copy of this grid: the element at (`x`, `y`) is moved to (`y`, `x`), and the sizes and looping flags of the X and Y axes are swapped.
+    pub fn transpose_xy(&self) -> GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>> {
+        self.permuted([Axis::Y, Axis::X, Axis::Z])
+    }
+}
+
 impl<D> GridData<Cartesian3D, D, CartesianGrid<Cartesian3D>> {
+    /// Converts this grid data to use a [`Cartesian2D`] coordinate system, moving the data buffer unchanged. See [`CartesianGrid::into_2d`].
+    ///
+    /// Returns `self` as an error if `size_z` is not 1.
+    pub fn reinterpret_2d(
+        self,
+    ) -> Result<GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>>, Self> {
+        if self.grid().size_z() != 1 {
+            return Err(self);
+        }
+        let (grid, data) = self.into_raw();
+        Ok(GridData::new(grid.into_2d().unwrap(), data))
+    }
+
+    /// Projects this grid onto the plane of the two other axes than `axis`: each element of the returned 2d grid is the result of `reduce` called on the column of elements along `axis`, by increasing coordinate on `axis`.
+    ///
+    /// The X and Y axes of the returned grid are the two remaining axes, in X, Y, Z order, with their sizes and looping flags: projecting along Z gives a top-down (X, Y) view, along Y a side (X, Z) view and along X a side (Y, Z) view.
+    pub fn project<E, F: Fn(&[&D]) -> E>(
+        &self,
+        axis: Axis,
+        reduce: F,
+    ) -> GridData<Cartesian2D, E, CartesianGrid<Cartesian2D>> {
+        let grid = self.grid();
+        let (u, v) = match axis {
+            Axis::X => (Axis::Y, Axis::Z),
+            Axis::Y => (Axis::X, Axis::Z),
+            Axis::Z => (Axis::X, Axis::Y),
+        };
+        let projected_grid = CartesianGrid::new_cartesian_2d(
+            grid.axis_size(u),
+            grid.axis_size(v),
+            grid.axis_looping(u),
+            grid.axis_looping(v),
+        );
+        let mut column = Vec::with_capacity(grid.axis_size(axis) as usize);
+        let data = projected_grid
+            .indexes()
+            .map(|index| {
+                let projected_pos = projected_grid.pos_from_index(index);
+                let mut coords = [0; 3];
+                coords[u as usize] = projected_pos.x;
+                coords[v as usize] = projected_pos.y;
+                column.clear();
+                for coord in 0..grid.axis_size(axis) {
+                    coords[axis as usize] = coord;
+                    column.push(self.get(grid.index_from_coords(coords[0], coords[1], coords[2])));
+                }
+                reduce(&column)
+            })
+            .collect();
+        GridData::new(projected_grid, data)
+    }
+
     /// Returns a reference to the data at this position.
     ///
     /// NO CHECK is done to verify that the given position is a valid position for this grid.
     #[inline]
     pub fn get_3d(&self, x: u32, y: u32, z: u32) -> &D {
-        &self.get(self.grid().index_from_coords(x, y, z))
+        self.get(self.grid().index_from_coords(x, y, z))
     }
 
     /// Returns a mutable reference to the data at this position.
@@ -227,4 +1266,314 @@ impl<D> GridData<Cartesian3D, D, CartesianGrid<Cartesian3D>> {
     pub fn get_3d_mut(&mut self, x: u32, y: u32, z: u32) -> &mut D {
         self.get_mut(self.grid().index_from_coords(x, y, z))
     }
+
+    /// Returns an iterator over the elements with y=`y` and z=`z`, by increasing x.
+    ///
+    /// NO CHECK is done to verify that `y` and `z` are valid for this grid.
+    pub fn iter_line_x(&self, y: u32, z: u32) -> impl Iterator<Item = &D> {
+        let grid = self.grid();
+        self.iter_strided(grid.index_from_coords(0, y, z), 1, grid.size_x())
+    }
+
+    /// Returns an iterator over the elements with x=`x` and z=`z`, by increasing y.
+    ///
+    /// NO CHECK is done to verify that `x` and `z` are valid for this grid.
+    pub fn iter_line_y(&self, x: u32, z: u32) -> impl Iterator<Item = &D> {
+        let grid = self.grid();
+        self.iter_strided(
+            grid.index_from_coords(x, 0, z),
+            grid.size_x(),
+            grid.size_y(),
+        )
+    }
+
+    /// Returns an iterator over the elements with x=`x` and y=`y`, by increasing z.
+    ///
+    /// NO CHECK is done to verify that `x` and `y` are valid for this grid.
+    pub fn iter_line_z(&self, x: u32, y: u32) -> impl Iterator<Item = &D> {
+        let grid = self.grid();
+        self.iter_strided(
+            grid.index_from_coords(x, y, 0),
+            grid.size_xy(),
+            grid.size_z(),
+        )
+    }
+
+    /// Returns a multi-line representation of the grid, layer by layer, each element being converted to a `char` by `f`.
+    ///
+    /// Layers are written by increasing z, each one starting with a `layer z=N` header line followed by its rows as in [`GridData::render_ascii`] (y increasing downward). Layers are separated by an empty line.
+    pub fn render_ascii_layers<F: Fn(&D) -> char>(&self, f: F) -> String {
+        self.render_ascii_layer_rows(false, f)
+    }
+
+    /// Same as [`GridData::render_ascii_layers`] but with y increasing upward in each layer: the first row of a layer is the row with the highest y.
+    pub fn render_ascii_layers_y_up<F: Fn(&D) -> char>(&self, f: F) -> String {
+        self.render_ascii_layer_rows(true, f)
+    }
+
+    fn render_ascii_layer_rows<F: Fn(&D) -> char>(&self, y_up: bool, f: F) -> String {
+        let (size_x, size_y, size_z) = self.grid().size();
+        let mut ascii =
+            String::with_capacity((size_x as usize + 1) * size_y as usize * size_z as usize);
+        for z in 0..size_z {
+            if z > 0 {
+                ascii.push('\n');
+            }
+            // Writing to a String cannot fail
+            let _ = writeln!(ascii, "layer z={}", z);
+            for row in 0..size_y {
+                let y = if y_up { size_y - 1 - row } else { row };
+                for x in 0..size_x {
+                    ascii.push(f(self.get_3d(x, y, z)));
+                }
+                ascii.push('\n');
+            }
+        }
+        ascii
+    }
+
+    /// Appends the content of the 2d grid data `layer` as a new layer at z=`size_z`, growing the grid by one on the Z axis. Looping flags are kept.
+    ///
+    /// Returns [`GridError::ShapeMismatch`] if `layer` does not have the X and Y sizes of this grid, and [`GridError::DimensionOverflow`] if the grown grid would be too big. The grid is left unchanged on error.
+    pub fn push_layer(
+        &mut self,
+        layer: GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>>,
+    ) -> Result<(), GridError> {
+        let grid = self.grid();
+        for axis in [Axis::X, Axis::Y] {
+            if layer.grid().axis_size(axis) != grid.axis_size(axis) {
+                return Err(GridError::ShapeMismatch {
+                    axis,
+                    expected: grid.axis_size(axis) as usize,
+                    got: layer.grid().axis_size(axis) as usize,
+                });
+            }
+        }
+        let (size_x, size_y, size_z) = grid.size();
+        let (looping_x, looping_y, looping_z) = grid.looping();
+        let new_size_z = size_z.checked_add(1).ok_or(GridError::DimensionOverflow {
+            size: (size_x, size_y, u32::MAX),
+        })?;
+        let grown_grid = CartesianGrid::try_new(
+            size_x,
+            size_y,
+            new_size_z,
+            looping_x,
+            looping_y,
+            looping_z,
+            Cartesian3D,
+        )?;
+        let (grid, data) = self.grid_and_data_mut();
+        *grid = grown_grid;
+        data.extend(layer.into_raw().1);
+        Ok(())
+    }
+
+    /// Cycles the layers of the grid along the Z axis by `by` layers, wrapping around: the layer at z moves to `(z + by) mod size_z`. A negative `by` cycles the layers towards the lower z.
+    ///
+    /// The grid is rotated in place, as a single contiguous rotation of its data.
+    pub fn cycle_z(&mut self, by: i32) {
+        let size_z = i64::from(self.grid().size_z());
+        let size_xy = self.grid().size_xy() as usize;
+        let shift = i64::from(by).rem_euclid(size_z) as usize;
+        self.as_mut_slice().rotate_right(shift * size_xy);
+    }
+}
+
+impl<D: Clone> GridData<Cartesian3D, D, CartesianGrid<Cartesian3D>> {
+    /// Returns a copy of the box starting at `min` and of size `size`, as a new non-looping [`GridData`] whose positions start at (0,0,0).
+    ///
+    /// If the box extends past the bounds of this grid, it is clamped and the returned grid has the clamped size. Returns `None` if the clamped box is empty: when `size` is 0 on an axis or `min` is past the edge of the grid.
+    pub fn subgrid(
+        &self,
+        min: CartesianPosition,
+        size: (u32, u32, u32),
+    ) -> Option<GridData<Cartesian3D, D, CartesianGrid<Cartesian3D>>> {
+        self.clamped_subgrid(&min, size)
+    }
+
+    /// Returns a copy of this grid with its axes permuted: the X, Y and Z axes of the copy are the axes of this grid given by [`AxisOrder::axes`], in this order. Sizes and looping flags are permuted along with the content.
+    ///
+    /// The copy is stored in the standard order, unlike an [`super::axis_swapped::AxisSwappedGrid`] which only changes the indexing.
+    pub fn transpose(
+        &self,
+        order: AxisOrder,
+    ) -> GridData<Cartesian3D, D, CartesianGrid<Cartesian3D>> {
+        self.permuted(order.axes())
+    }
+
+    /// Returns a copy of the layer at `z`, as a new 2d [`GridData`] with the X and Y sizes and looping flags of this grid.
+    ///
+    /// Panics if `z` is not a valid z coordinate for this grid.
+    pub fn layer(&self, z: u32) -> GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>> {
+        let grid = self.grid();
+        let (looping_x, looping_y, _) = grid.looping();
+        let layer_grid =
+            CartesianGrid::new_cartesian_2d(grid.size_x(), grid.size_y(), looping_x, looping_y);
+        let size_xy = grid.size_xy() as usize;
+        let start = z as usize * size_xy;
+        GridData::new(layer_grid, self.as_slice()[start..start + size_xy].to_vec())
+    }
+
+    /// Replaces the layer at `z` by a copy of the content of the 2d grid data `layer`.
+    ///
+    /// Returns [`GridError::ShapeMismatch`] if `layer` does not have the X and Y sizes of this grid, and [`GridError::PositionOutOfBounds`] if `z` is not a valid z coordinate for this grid.
+    pub fn set_layer(
+        &mut self,
+        z: u32,
+        layer: &GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>>,
+    ) -> Result<(), GridError> {
+        let grid = self.grid();
+        for axis in [Axis::X, Axis::Y] {
+            if layer.grid().axis_size(axis) != grid.axis_size(axis) {
+                return Err(GridError::ShapeMismatch {
+                    axis,
+                    expected: grid.axis_size(axis) as usize,
+                    got: layer.grid().axis_size(axis) as usize,
+                });
+            }
+        }
+        if z >= grid.size_z() {
+            return Err(GridError::PositionOutOfBounds {
+                position: (0, 0, z),
+                size: grid.size(),
+            });
+        }
+        let size_xy = grid.size_xy() as usize;
+        let start = z as usize * size_xy;
+        self.as_mut_slice()[start..start + size_xy].clone_from_slice(layer.as_slice());
+        Ok(())
+    }
+}
+
+impl<D: Clone + PartialEq> GridData<Cartesian3D, D, CartesianGrid<Cartesian3D>> {
+    /// Returns the distinct copies of this grid under the 24 rotations of the cube, starting with an unrotated copy.
+    ///
+    /// Sizes and looping flags are rotated along with the content. Rotated copies equal to a previous one are skipped, so a grid with symmetries returns less than 24 copies.
+    pub fn all_orientations(&self) -> Vec<GridData<Cartesian3D, D, CartesianGrid<Cartesian3D>>> {
+        let grid = self.grid();
+        let size = grid.size();
+        let size = [size.0, size.1, size.2];
+        let looping = grid.looping();
+        let looping = [looping.0, looping.1, looping.2];
+
+        let mut orientations: Vec<GridData<Cartesian3D, D, CartesianGrid<Cartesian3D>>> =
+            Vec::with_capacity(24);
+        for (axes, parity) in AXES_PERMUTATIONS {
+            for flips in 0..8u8 {
+                let flipped = |axis: usize| flips & (1 << axis) != 0;
+                // Proper rotations only: mirrors from the permutation and from the flips must cancel out
+                if (parity + flips.count_ones()) % 2 != 0 {
+                    continue;
+                }
+                // New axis `i` is the old axis `axes[i]`, reversed if `flipped(i)`
+                let rotated_grid = CartesianGrid::new_cartesian_3d(
+                    size[axes[0]],
+                    size[axes[1]],
+                    size[axes[2]],
+                    looping[axes[0]],
+                    looping[axes[1]],
+                    looping[axes[2]],
+                );
+                let rotated = rotated_grid.grid_data_from_fn(|pos| {
+                    let new_coords = [pos.x, pos.y, pos.z];
+                    let mut old_coords = [0; 3];
+                    for (i, &axis) in axes.iter().enumerate() {
+                        old_coords[axis] = match flipped(i) {
+                            true => size[axis] - 1 - new_coords[i],
+                            false => new_coords[i],
+                        };
+                    }
+                    self.get_3d(old_coords[0], old_coords[1], old_coords[2])
+                        .clone()
+                });
+                if !orientations.contains(&rotated) {
+                    orientations.push(rotated);
+                }
+            }
+        }
+        orientations
+    }
+}
+
+impl GridData<Cartesian2D, f32, CartesianGrid<Cartesian2D>> {
+    /// Returns the value of the grid at the continuous position (`x`, `y`), interpolated between the 4 surrounding elements. Element values are located at integer coordinates.
+    ///
+    /// Coordinates wrap around looping axes and are clamped to the grid on non-looping axes.
+    pub fn sample_bilinear(&self, x: f32, y: f32) -> f32 {
+        let grid = self.grid();
+        let (x0, x1, tx) = sample_axis(x, grid.size_x(), grid.looping().0);
+        let (y0, y1, ty) = sample_axis(y, grid.size_y(), grid.looping().1);
+        let value = |x, y| *self.get(grid.index_from_coords(x, y, 0));
+        lerp(
+            lerp(value(x0, y0), value(x1, y0), tx),
+            lerp(value(x0, y1), value(x1, y1), tx),
+            ty,
+        )
+    }
+}
+
+impl GridData<Cartesian3D, f32, CartesianGrid<Cartesian3D>> {
+    /// Returns the value of the grid at the continuous position (`x`, `y`, `z`), interpolated between the 8 surrounding elements. Element values are located at integer coordinates.
+    ///
+    /// Coordinates wrap around looping axes and are clamped to the grid on non-looping axes.
+    pub fn sample_trilinear(&self, x: f32, y: f32, z: f32) -> f32 {
+        let grid = self.grid();
+        let (looping_x, looping_y, looping_z) = grid.looping();
+        let (x0, x1, tx) = sample_axis(x, grid.size_x(), looping_x);
+        let (y0, y1, ty) = sample_axis(y, grid.size_y(), looping_y);
+        let (z0, z1, tz) = sample_axis(z, grid.size_z(), looping_z);
+        let value = |x, y, z| *self.get(grid.index_from_coords(x, y, z));
+        let layer = |z| {
+            lerp(
+                lerp(value(x0, y0, z), value(x1, y0, z), tx),
+                lerp(value(x0, y1, z), value(x1, y1, z), tx),
+                ty,
+            )
+        };
+        lerp(layer(z0), layer(z1), tz)
+    }
+}
+
+/// Permutations of the axes, with their parity
+const AXES_PERMUTATIONS: [([usize; 3], u32); 6] = [
+    ([0, 1, 2], 0),
+    ([1, 2, 0], 0),
+    ([2, 0, 1], 0),
+    ([1, 0, 2], 1),
+    ([0, 2, 1], 1),
+    ([2, 1, 0], 1),
+];
+
+/// Returns the `(y, z)` coordinates of the row at index `row` if it intersects the (clamped) `region`
+fn region_row(region: &GridRegion, row: usize, size_y: u32) -> Option<(u32, u32)> {
+    let y = (row % size_y as usize) as u32;
+    let z = (row / size_y as usize) as u32;
+    (!region.is_empty()
+        && (region.min.y..region.max.y).contains(&y)
+        && (region.min.z..region.max.z).contains(&z))
+    .then_some((y, z))
+}
+
+/// Returns the coordinates of the two elements surrounding the continuous coordinate `coord` on an axis of size `size`, and the interpolation factor between them
+fn sample_axis(coord: f32, size: u32, looping: bool) -> (u32, u32, f32) {
+    if looping {
+        // Casting truncates towards 0, `f32::floor` is not available without `std`
+        let truncated = coord as i64;
+        let floor = match (truncated as f32) > coord {
+            true => truncated - 1,
+            false => truncated,
+        };
+        let c0 = floor.rem_euclid(i64::from(size)) as u32;
+        (c0, (c0 + 1) % size, coord - floor as f32)
+    } else {
+        let coord = coord.clamp(0., (size - 1) as f32);
+        let c0 = coord as u32;
+        (c0, (c0 + 1).min(size - 1), coord - c0 as f32)
+    }
+}
+
+#[inline]
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
 }
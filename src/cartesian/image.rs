@@ -0,0 +1,100 @@
+use ::image::{Rgba, RgbaImage};
+
+use crate::{
+    error::GridError,
+    grid::{Grid, GridData},
+};
+
+use super::{coordinates::Cartesian2D, grid::CartesianGrid};
+
+/// Location of the grid origin (x=0, y=0) in an image.
+///
+/// Pixel buffers are always read and written row by row, starting with the top row of the image.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageOrigin {
+    /// The grid origin is the top-left pixel: the Y axis points down in the image.
+    #[default]
+    TopLeft,
+    /// The grid origin is the bottom-left pixel: the Y axis points up in the image.
+    BottomLeft,
+}
+
+impl ImageOrigin {
+    /// Maps an image row to a grid y coordinate, and conversely
+    #[inline]
+    fn map_row(&self, row: u32, height: u32) -> u32 {
+        match self {
+            ImageOrigin::TopLeft => row,
+            ImageOrigin::BottomLeft => height - 1 - row,
+        }
+    }
+}
+
+impl CartesianGrid<Cartesian2D> {
+    /// Creates a non-looping grid of size `width` x `height` and its data, from a buffer of `pixels` transformed by `f`.
+    ///
+    /// `pixels` is read row by row starting with the top row of the image, and `origin` gives the location of the grid origin in the image.
+    ///
+    /// Returns [`GridError::LengthMismatch`] if `pixels` does not contain exactly `width` x `height` pixels.
+    pub fn from_image_fn<P: Copy, D, F: Fn(P) -> D>(
+        width: u32,
+        height: u32,
+        pixels: &[P],
+        origin: ImageOrigin,
+        f: F,
+    ) -> Result<GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>>, GridError> {
+        let grid = Self::try_new(width, height, 1, false, false, false, Cartesian2D)?;
+        if pixels.len() != grid.total_size() {
+            return Err(GridError::LengthMismatch {
+                expected: grid.total_size(),
+                got: pixels.len(),
+            });
+        }
+        let mut data = Vec::with_capacity(pixels.len());
+        for y in 0..height {
+            let row = origin.map_row(y, height);
+            let start = (row * width) as usize;
+            data.extend(pixels[start..start + width as usize].iter().map(|p| f(*p)));
+        }
+        Ok(GridData::new(grid, data))
+    }
+
+    /// Creates a non-looping grid and its data from an [`RgbaImage`], with each pixel transformed by `f`.
+    ///
+    /// See [`CartesianGrid::from_image_fn`].
+    pub fn from_rgba_image<D, F: Fn(Rgba<u8>) -> D>(
+        image: &RgbaImage,
+        origin: ImageOrigin,
+        f: F,
+    ) -> Result<GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>>, GridError> {
+        let pixels: Vec<Rgba<u8>> = image.pixels().copied().collect();
+        Self::from_image_fn(image.width(), image.height(), &pixels, origin, f)
+    }
+}
+
+impl<D> GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>> {
+    /// Returns a buffer of pixels, one per element transformed by `f`.
+    ///
+    /// The buffer is written row by row starting with the top row of the image, and `origin` gives the location of the grid origin in the image. Only the first layer (z=0) of the grid is used.
+    pub fn to_pixels<P, F: Fn(&D) -> P>(&self, origin: ImageOrigin, f: F) -> Vec<P> {
+        let (width, height) = (self.grid().size_x(), self.grid().size_y());
+        let mut pixels = Vec::with_capacity((width * height) as usize);
+        for row in 0..height {
+            let y = origin.map_row(row, height);
+            pixels.extend((0..width).map(|x| f(self.get_2d(x, y))));
+        }
+        pixels
+    }
+
+    /// Returns an [`RgbaImage`] with one pixel per element transformed by `f`.
+    ///
+    /// See [`GridData::to_pixels`].
+    pub fn to_rgba_image<F: Fn(&D) -> Rgba<u8>>(&self, origin: ImageOrigin, f: F) -> RgbaImage {
+        let raw = self
+            .to_pixels(origin, f)
+            .into_iter()
+            .flat_map(|pixel| pixel.0)
+            .collect();
+        RgbaImage::from_raw(self.grid().size_x(), self.grid().size_y(), raw).unwrap()
+    }
+}
@@ -6,3 +6,48 @@ pub mod grid;
 
 /// Cartesian implementations of [`crate::grid::GridData`]
 pub mod grid_data;
+
+/// Axis-aligned regions of cartesian grids
+pub mod region;
+
+/// Disjoint mutable views over the rows of 2d cartesian grid data
+pub mod rows_view;
+
+/// Cartesian grid data stored inline in a fixed-size array
+pub mod fixed_grid_data;
+
+/// Conversions between 2d cartesian [`crate::grid::GridData`] and images
+#[cfg(feature = "image")]
+pub mod image;
+
+/// Conversions between cartesian positions and deltas and `glam` vectors
+#[cfg(feature = "glam")]
+pub mod glam;
+
+/// Conversions between cartesian [`crate::grid::GridData`] and `ndarray` arrays
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+
+/// Random positions and indexes in cartesian grids
+#[cfg(feature = "rand")]
+pub mod rand;
+
+/// Cartesian grid with positions starting at an arbitrary origin
+pub mod offset;
+
+/// Cartesian grid indexed in Morton order
+pub mod morton;
+
+/// Cartesian grid indexed with a permutation of its axes
+pub mod axis_swapped;
+
+/// Serde representation of cartesian [`crate::grid::GridData`] as nested rows and layers
+#[cfg(feature = "serde")]
+pub mod serde_nested;
+
+/// Versioned serde representation of [`grid::CartesianGrid`]
+#[cfg(feature = "serde")]
+pub mod serde_versioned;
+
+/// Cartesian grid with a coordinate system defined at runtime
+pub mod custom;
@@ -0,0 +1,276 @@
+use crate::{
+    coordinate_system::CoordinateSystem,
+    direction::{Direction, DirectionTrait},
+    error::GridError,
+    grid::{Grid, GridData, GridIndex, NodeRef},
+};
+use alloc::{vec, vec::Vec};
+
+use super::{
+    coordinates::{CartesianCoordinates, CartesianPosition},
+    grid::CartesianGrid,
+};
+
+#[cfg(feature = "bevy")]
+use bevy::ecs::component::Component;
+#[cfg(feature = "reflect")]
+use bevy::{ecs::reflect::ReflectComponent, reflect::Reflect};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Definition of a cartesian grid whose elements are indexed in Morton order (Z-order), so that elements close in space are also close in memory.
+///
+/// Sizes on each axis must be powers of two, but can differ between axes: the bits of each coordinate are interleaved as long as the axis has bits left.
+///
+/// Positions are the same as in a [`CartesianGrid`] of the same size, only the mapping between positions and indexes differs.
+///
+/// Neighbours are computed on the Morton codes directly, which is cheaper than going through positions, so neighbourhood-based algorithms (flood fills, cellular automata, ...) benefit from the memory locality. Converting between positions and indexes requires interleaving bits and is slower than in a [`CartesianGrid`]: prefer a [`CartesianGrid`] when elements are mostly accessed by position. The `morton` benchmark (`cargo bench --bench morton`) compares both grids on these access patterns.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Component, Default))]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MortonGrid<C: CoordinateSystem> {
+    grid: CartesianGrid<C>,
+    /// Bits of a Morton code used by each axis
+    axis_masks: [u64; 3],
+}
+
+impl<C: CartesianCoordinates> MortonGrid<C> {
+    /// Creates a new [`MortonGrid`]
+    ///
+    /// Panics if any size is not a power of two.
+    pub fn new(
+        size_x: u32,
+        size_y: u32,
+        size_z: u32,
+        looping_x: bool,
+        looping_y: bool,
+        looping_z: bool,
+        coord_system: C,
+    ) -> MortonGrid<C> {
+        assert!(
+            size_x.is_power_of_two() && size_y.is_power_of_two() && size_z.is_power_of_two(),
+            "MortonGrid sizes must be powers of two"
+        );
+        let bits = [
+            size_x.trailing_zeros(),
+            size_y.trailing_zeros(),
+            size_z.trailing_zeros(),
+        ];
+        let mut axis_masks = [0; 3];
+        let mut code_bit = 0;
+        for level in 0..bits.iter().max().copied().unwrap_or(0) {
+            for (axis, axis_bits) in bits.iter().enumerate() {
+                if level < *axis_bits {
+                    axis_masks[axis] |= 1 << code_bit;
+                    code_bit += 1;
+                }
+            }
+        }
+        Self {
+            grid: CartesianGrid::new(
+                size_x,
+                size_y,
+                size_z,
+                looping_x,
+                looping_y,
+                looping_z,
+                coord_system,
+            ),
+            axis_masks,
+        }
+    }
+
+    /// Returns the [`CartesianGrid`] with the same sizes and looping flags as this grid, which can be used to reason about positions.
+    #[inline]
+    pub fn cartesian_grid(&self) -> &CartesianGrid<C> {
+        &self.grid
+    }
+
+    /// Returns the index from a grid position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn index_from_coords(&self, x: u32, y: u32, z: u32) -> GridIndex {
+        (deposit_bits(x, self.axis_masks[0])
+            | deposit_bits(y, self.axis_masks[1])
+            | deposit_bits(z, self.axis_masks[2])) as GridIndex
+    }
+
+    /// Returns the index from a grid position.
+    ///
+    /// NO CHECK is done to verify that the given `grid_position` is a valid position for this grid.
+    #[inline]
+    pub fn index_from_pos(&self, grid_position: &CartesianPosition) -> GridIndex {
+        self.index_from_coords(grid_position.x, grid_position.y, grid_position.z)
+    }
+
+    /// Returns a [`CartesianPosition`] from the index of an element in this [`MortonGrid`].
+    ///
+    /// NO CHECK is done to verify that the given index is a valid index for this grid.
+    #[inline]
+    pub fn pos_from_index(&self, grid_index: GridIndex) -> CartesianPosition {
+        let code = grid_index as u64;
+        CartesianPosition {
+            x: extract_bits(code, self.axis_masks[0]),
+            y: extract_bits(code, self.axis_masks[1]),
+            z: extract_bits(code, self.axis_masks[2]),
+        }
+    }
+
+    /// Returns the index of the neighbour of the element at `grid_index` in `direction`, or `None` if the destination is not in the grid.
+    ///
+    /// Unit moves are computed on the Morton code itself, without decoding the position: the bits of the moved axis are incremented or decremented while the bits of the other axes are kept.
+    fn neighbour_index(&self, grid_index: GridIndex, direction: Direction) -> Option<GridIndex> {
+        // Directions which are not in the coordinate system have no neighbour
+        if *self.grid.coord_system().deltas().get(direction as usize)? != direction.delta() {
+            return self
+                .grid
+                .get_next_pos_in_direction(&MortonGrid::pos_from_index(self, grid_index), direction)
+                .map(|next_pos| MortonGrid::index_from_pos(self, &next_pos));
+        }
+        let axis = direction.axis();
+        let mask = self.axis_masks[axis as usize];
+        let code = grid_index as u64;
+        let axis_bits = code & mask;
+        // Sizes are powers of two: the last coordinate of an axis has all the bits of its mask set
+        let on_edge = match direction.is_positive() {
+            true => axis_bits == mask,
+            false => axis_bits == 0,
+        };
+        if on_edge && !self.grid.axis_looping(axis) {
+            return None;
+        }
+        // Setting the bits of the other axes carries the increment over them. Wrapping around on looping axes comes from the mask.
+        let moved_bits = match direction.is_positive() {
+            true => (code | !mask).wrapping_add(1) & mask,
+            false => axis_bits.wrapping_sub(1) & mask,
+        };
+        Some(((code & !mask) | moved_bits) as GridIndex)
+    }
+
+    /// Creates a default [`GridData`] with the size of the [`MortonGrid`] with each element value set to its default one.
+    pub fn default_grid_data<D: Default + Clone>(&self) -> GridData<C, D, MortonGrid<C>> {
+        GridData::new(self.clone(), vec![D::default(); self.total_size()])
+    }
+
+    /// Creates a [`GridData`] with the size of the [`MortonGrid`] with each element value being a copy of the given one.
+    pub fn new_grid_data<D: Clone>(&self, element: D) -> GridData<C, D, MortonGrid<C>> {
+        GridData::new(self.clone(), vec![element; self.total_size()])
+    }
+}
+
+impl<C: CartesianCoordinates> Grid<C> for MortonGrid<C> {
+    type Position = C::Position;
+
+    #[inline]
+    fn coord_system(&self) -> &C {
+        self.grid.coord_system()
+    }
+
+    #[inline]
+    fn directions_count(&self) -> usize {
+        self.grid.directions_count()
+    }
+
+    #[inline]
+    fn total_size(&self) -> usize {
+        self.grid.total_size()
+    }
+
+    fn get_neighbours_in_all_directions(
+        &self,
+        grid_index: GridIndex,
+        neighbours_buffer: &mut Vec<Option<GridIndex>>,
+    ) {
+        for dir in self.grid.coord_system().directions() {
+            neighbours_buffer[usize::from(*dir)] = self.neighbour_index(grid_index, *dir);
+        }
+    }
+
+    #[inline]
+    fn neighbour(&self, index: GridIndex, direction: Direction) -> Option<GridIndex> {
+        self.neighbour_index(index, direction)
+    }
+
+    #[inline]
+    fn index_from_pos(&self, grid_position: &C::Position) -> GridIndex {
+        MortonGrid::index_from_pos(self, &(*grid_position).into())
+    }
+
+    #[inline]
+    fn pos_from_index(&self, grid_index: GridIndex) -> C::Position {
+        C::position_from_cartesian(MortonGrid::pos_from_index(self, grid_index))
+    }
+
+    #[inline]
+    fn is_valid_pos(&self, grid_position: &C::Position) -> bool {
+        self.grid.is_valid_pos(grid_position)
+    }
+}
+
+impl<C: CartesianCoordinates> NodeRef<C, MortonGrid<C>> for CartesianPosition {
+    #[inline]
+    fn to_index(&self, grid: &MortonGrid<C>) -> GridIndex {
+        grid.index_from_pos(self)
+    }
+
+    #[inline]
+    fn try_to_index(&self, grid: &MortonGrid<C>) -> Result<GridIndex, GridError> {
+        grid.cartesian_grid().check_coords(self.x, self.y, self.z)?;
+        Ok(grid.index_from_pos(self))
+    }
+}
+
+impl<C: CartesianCoordinates, D> GridData<C, D, MortonGrid<C>> {
+    /// Returns a reference to the element at this position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn get_from_pos(&self, pos: &CartesianPosition) -> &D {
+        self.get(self.grid().index_from_pos(pos))
+    }
+
+    /// Returns a mutable reference to the element at this position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn get_mut_from_pos(&mut self, pos: &CartesianPosition) -> &mut D {
+        self.get_mut(self.grid().index_from_pos(pos))
+    }
+}
+
+/// Spreads the low bits of `value` onto the set bits of `mask`
+#[inline]
+fn deposit_bits(value: u32, mask: u64) -> u64 {
+    let mut result = 0;
+    let mut remaining_mask = mask;
+    let mut value_bit = 0;
+    while remaining_mask != 0 {
+        let lowest_bit = remaining_mask & remaining_mask.wrapping_neg();
+        if (value >> value_bit) & 1 != 0 {
+            result |= lowest_bit;
+        }
+        value_bit += 1;
+        remaining_mask &= remaining_mask - 1;
+    }
+    result
+}
+
+/// Gathers the bits of `code` located on the set bits of `mask` into the low bits of the result
+#[inline]
+fn extract_bits(code: u64, mask: u64) -> u32 {
+    let mut result = 0;
+    let mut remaining_mask = mask;
+    let mut value_bit = 0;
+    while remaining_mask != 0 {
+        let lowest_bit = remaining_mask & remaining_mask.wrapping_neg();
+        if code & lowest_bit != 0 {
+            result |= 1 << value_bit;
+        }
+        value_bit += 1;
+        remaining_mask &= remaining_mask - 1;
+    }
+    result
+}
@@ -0,0 +1,69 @@
+//! Arrays follow the C order of the data buffer of a [`GridData`](crate::grid::GridData): a 3d grid maps to an array of shape `[size_z, size_y, size_x]`, and a 2d grid to an array of shape `[size_y, size_x]`. The element at position (`x`, `y`, `z`) is the array element `[z, y, x]`.
+
+use ::ndarray::{Array2, Array3, ArrayView2, ArrayView3};
+
+use crate::{error::GridError, grid::GridData};
+
+use super::{
+    coordinates::{Cartesian2D, Cartesian3D},
+    grid::CartesianGrid,
+};
+
+/// Converts the sizes of an array shape, given in grid axis order, to grid sizes
+fn grid_size(x: usize, y: usize, z: usize) -> Result<(u32, u32, u32), GridError> {
+    match (u32::try_from(x), u32::try_from(y), u32::try_from(z)) {
+        (Ok(x), Ok(y), Ok(z)) => Ok((x, y, z)),
+        _ => Err(GridError::DimensionOverflow {
+            size: (
+                u32::try_from(x).unwrap_or(u32::MAX),
+                u32::try_from(y).unwrap_or(u32::MAX),
+                u32::try_from(z).unwrap_or(u32::MAX),
+            ),
+        }),
+    }
+}
+
+impl<D: Clone> GridData<Cartesian3D, D, CartesianGrid<Cartesian3D>> {
+    /// Returns a copy of the data as an array of shape `[size_z, size_y, size_x]`, in C order: the element at position (`x`, `y`, `z`) is `array[[z, y, x]]`.
+    ///
+    /// Inverse of [`GridData::from_ndarray`].
+    pub fn to_ndarray(&self) -> Array3<D> {
+        let (size_x, size_y, size_z) = self.grid().size();
+        let shape = (size_z as usize, size_y as usize, size_x as usize);
+        Array3::from_shape_vec(shape, self.as_slice().to_vec())
+            .expect("the data buffer length is equal to the grid total size")
+    }
+
+    /// Creates a non-looping 3d grid and its data from an array of shape `[size_z, size_y, size_x]`: the array element `[z, y, x]` is the element at position (`x`, `y`, `z`).
+    ///
+    /// Inverse of [`GridData::to_ndarray`]. Returns [`GridError::ZeroDimension`] if the array is empty and [`GridError::DimensionOverflow`] if it is too big for a grid.
+    pub fn from_ndarray(array: ArrayView3<D>) -> Result<Self, GridError> {
+        let (size_z, size_y, size_x) = array.dim();
+        let (size_x, size_y, size_z) = grid_size(size_x, size_y, size_z)?;
+        let grid =
+            CartesianGrid::try_new(size_x, size_y, size_z, false, false, false, Cartesian3D)?;
+        Ok(GridData::new(grid, array.iter().cloned().collect()))
+    }
+}
+
+impl<D: Clone> GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>> {
+    /// Returns a copy of the data as an array of shape `[size_y, size_x]`, in C order: the element at position (`x`, `y`) is `array[[y, x]]`.
+    ///
+    /// Inverse of [`GridData::from_ndarray_2d`].
+    pub fn to_ndarray_2d(&self) -> Array2<D> {
+        let (size_x, size_y, _) = self.grid().size();
+        let shape = (size_y as usize, size_x as usize);
+        Array2::from_shape_vec(shape, self.as_slice().to_vec())
+            .expect("the data buffer length is equal to the grid total size")
+    }
+
+    /// Creates a non-looping 2d grid and its data from an array of shape `[size_y, size_x]`: the array element `[y, x]` is the element at position (`x`, `y`).
+    ///
+    /// Inverse of [`GridData::to_ndarray_2d`]. Returns [`GridError::ZeroDimension`] if the array is empty and [`GridError::DimensionOverflow`] if it is too big for a grid.
+    pub fn from_ndarray_2d(array: ArrayView2<D>) -> Result<Self, GridError> {
+        let (size_y, size_x) = array.dim();
+        let (size_x, size_y, _) = grid_size(size_x, size_y, 1)?;
+        let grid = CartesianGrid::try_new(size_x, size_y, 1, false, false, false, Cartesian2D)?;
+        Ok(GridData::new(grid, array.iter().cloned().collect()))
+    }
+}
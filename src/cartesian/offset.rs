@@ -0,0 +1,232 @@
+use crate::{
+    coordinate_system::CoordinateSystem,
+    direction::Direction,
+    error::GridError,
+    grid::{Grid, GridData, GridIndex, NodeRef},
+};
+use alloc::{vec, vec::Vec};
+
+use super::{
+    coordinates::{CartesianCoordinates, CartesianPosition},
+    grid::CartesianGrid,
+};
+
+#[cfg(feature = "bevy")]
+use bevy::ecs::component::Component;
+#[cfg(feature = "reflect")]
+use bevy::{ecs::reflect::ReflectComponent, reflect::Reflect};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Definition of a cartesian grid whose positions start at `origin` instead of (0,0,0), for grids covering a part of a larger world.
+///
+/// Positions given to and returned by this grid are world positions: the position `origin` is the element at index 0. Storage and indexes are the same as in the inner [`CartesianGrid`], which uses local positions starting at (0,0,0).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Component, Default))]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OffsetGrid<C: CoordinateSystem> {
+    grid: CartesianGrid<C>,
+    origin: CartesianPosition,
+}
+
+impl<C: CartesianCoordinates> OffsetGrid<C> {
+    /// Creates a new [`OffsetGrid`] with the sizes and looping flags of `grid`, whose first element is at the world position `origin`
+    ///
+    /// Panics if the world position of the last element of the grid does not fit in a [`CartesianPosition`].
+    pub fn new(grid: CartesianGrid<C>, origin: CartesianPosition) -> OffsetGrid<C> {
+        let (size_x, size_y, size_z) = grid.size();
+        assert!(
+            origin.x.checked_add(size_x - 1).is_some()
+                && origin.y.checked_add(size_y - 1).is_some()
+                && origin.z.checked_add(size_z - 1).is_some(),
+            "grid of size {}x{}x{} at origin {:?} extends past the largest world position",
+            size_x,
+            size_y,
+            size_z,
+            origin
+        );
+        Self { grid, origin }
+    }
+
+    /// Returns the inner [`CartesianGrid`], which uses local positions starting at (0,0,0)
+    #[inline]
+    pub fn cartesian_grid(&self) -> &CartesianGrid<C> {
+        &self.grid
+    }
+
+    /// Returns the world position of the first element of the grid
+    #[inline]
+    pub fn origin(&self) -> CartesianPosition {
+        self.origin
+    }
+
+    /// Converts a world position into a local position of the inner [`CartesianGrid`], or returns `None` if the position is before the origin on any axis.
+    #[inline]
+    pub fn to_local(&self, world_position: &CartesianPosition) -> Option<CartesianPosition> {
+        Some(CartesianPosition::new(
+            world_position.x.checked_sub(self.origin.x)?,
+            world_position.y.checked_sub(self.origin.y)?,
+            world_position.z.checked_sub(self.origin.z)?,
+        ))
+    }
+
+    /// Converts a local position of the inner [`CartesianGrid`] into a world position, or returns `None` if the world position does not fit in a [`CartesianPosition`].
+    ///
+    /// The world positions of the elements of the grid always fit.
+    #[inline]
+    pub fn to_world(&self, local_position: &CartesianPosition) -> Option<CartesianPosition> {
+        Some(CartesianPosition::new(
+            self.origin.x.checked_add(local_position.x)?,
+            self.origin.y.checked_add(local_position.y)?,
+            self.origin.z.checked_add(local_position.z)?,
+        ))
+    }
+
+    /// Returns `true` if the world position is in the grid
+    pub fn contains(&self, world_position: &CartesianPosition) -> bool {
+        self.to_local(world_position)
+            .is_some_and(|local| self.grid.check_coords(local.x, local.y, local.z).is_ok())
+    }
+
+    /// Returns the index from a world position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn index_from_pos(&self, world_position: &CartesianPosition) -> GridIndex {
+        self.grid.index_from_coords(
+            world_position.x - self.origin.x,
+            world_position.y - self.origin.y,
+            world_position.z - self.origin.z,
+        )
+    }
+
+    /// Returns the world position of the element at `grid_index`.
+    ///
+    /// NO CHECK is done to verify that the given index is a valid index for this grid.
+    #[inline]
+    pub fn pos_from_index(&self, grid_index: GridIndex) -> CartesianPosition {
+        self.to_world(&self.grid.pos_from_index(grid_index))
+            .expect("the elements of the grid have a world position")
+    }
+
+    /// Returns the next world position in the grid when moving 1 unit in `direction` from `world_position`.
+    ///
+    /// Returns `None` if the destination is not in the grid.
+    ///
+    /// NO CHECK is done to verify that the given `world_position` is a valid position for this grid.
+    pub fn get_next_pos_in_direction(
+        &self,
+        world_position: &CartesianPosition,
+        direction: Direction,
+    ) -> Option<CartesianPosition> {
+        self.grid
+            .get_next_pos_in_direction(&self.to_local(world_position)?, direction)
+            .and_then(|next_pos| self.to_world(&next_pos))
+    }
+
+    /// Creates a default [`GridData`] with the size of the [`OffsetGrid`] with each element value set to its default one.
+    pub fn default_grid_data<D: Default + Clone>(&self) -> GridData<C, D, OffsetGrid<C>> {
+        GridData::new(self.clone(), vec![D::default(); self.total_size()])
+    }
+
+    /// Creates a [`GridData`] with the size of the [`OffsetGrid`] with each element value being a copy of the given one.
+    pub fn new_grid_data<D: Clone>(&self, element: D) -> GridData<C, D, OffsetGrid<C>> {
+        GridData::new(self.clone(), vec![element; self.total_size()])
+    }
+}
+
+impl<C: CartesianCoordinates> CartesianGrid<C> {
+    /// Returns an [`OffsetGrid`] with the sizes and looping flags of this grid, whose first element is at the world position `origin`
+    ///
+    /// Panics if the world position of the last element of the grid does not fit in a [`CartesianPosition`].
+    pub fn with_origin(self, origin: CartesianPosition) -> OffsetGrid<C> {
+        OffsetGrid::new(self, origin)
+    }
+}
+
+impl<C: CartesianCoordinates> Grid<C> for OffsetGrid<C> {
+    type Position = C::Position;
+
+    #[inline]
+    fn coord_system(&self) -> &C {
+        self.grid.coord_system()
+    }
+
+    #[inline]
+    fn directions_count(&self) -> usize {
+        self.grid.directions_count()
+    }
+
+    #[inline]
+    fn total_size(&self) -> usize {
+        self.grid.total_size()
+    }
+
+    #[inline]
+    fn get_neighbours_in_all_directions(
+        &self,
+        grid_index: GridIndex,
+        neighbours_buffer: &mut Vec<Option<GridIndex>>,
+    ) {
+        self.grid
+            .get_neighbours_in_all_directions(grid_index, neighbours_buffer)
+    }
+
+    #[inline]
+    fn neighbour(&self, index: GridIndex, direction: Direction) -> Option<GridIndex> {
+        self.grid.neighbour(index, direction)
+    }
+
+    #[inline]
+    fn index_from_pos(&self, grid_position: &C::Position) -> GridIndex {
+        OffsetGrid::index_from_pos(self, &(*grid_position).into())
+    }
+
+    #[inline]
+    fn pos_from_index(&self, grid_index: GridIndex) -> C::Position {
+        C::position_from_cartesian(OffsetGrid::pos_from_index(self, grid_index))
+    }
+
+    #[inline]
+    fn is_valid_pos(&self, grid_position: &C::Position) -> bool {
+        self.contains(&(*grid_position).into())
+    }
+}
+
+impl<C: CartesianCoordinates> NodeRef<C, OffsetGrid<C>> for CartesianPosition {
+    #[inline]
+    fn to_index(&self, grid: &OffsetGrid<C>) -> GridIndex {
+        grid.index_from_pos(self)
+    }
+
+    #[inline]
+    fn try_to_index(&self, grid: &OffsetGrid<C>) -> Result<GridIndex, GridError> {
+        match grid.contains(self) {
+            true => Ok(grid.index_from_pos(self)),
+            false => Err(GridError::PositionOutOfBounds {
+                position: (self.x, self.y, self.z),
+                size: grid.cartesian_grid().size(),
+            }),
+        }
+    }
+}
+
+impl<C: CartesianCoordinates, D> GridData<C, D, OffsetGrid<C>> {
+    /// Returns a reference to the element at this world position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn get_from_pos(&self, pos: &CartesianPosition) -> &D {
+        self.get(self.grid().index_from_pos(pos))
+    }
+
+    /// Returns a mutable reference to the element at this world position.
+    ///
+    /// NO CHECK is done to verify that the given position is a valid position for this grid.
+    #[inline]
+    pub fn get_mut_from_pos(&mut self, pos: &CartesianPosition) -> &mut D {
+        self.get_mut(self.grid().index_from_pos(pos))
+    }
+}
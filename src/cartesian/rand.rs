@@ -0,0 +1,48 @@
+use ::rand::Rng;
+
+use crate::grid::{Grid, GridData, GridIndex};
+
+use super::{
+    coordinates::{CartesianCoordinates, CartesianPosition},
+    grid::CartesianGrid,
+};
+
+impl<C: CartesianCoordinates> CartesianGrid<C> {
+    /// Returns the index of an element of the grid drawn uniformly at random, in `0..total_size`
+    pub fn random_index<R: Rng + ?Sized>(&self, rng: &mut R) -> GridIndex {
+        rng.gen_range(0..self.total_size()) as GridIndex
+    }
+
+    /// Returns a position of the grid drawn uniformly at random, edges included
+    pub fn random_position<R: Rng + ?Sized>(&self, rng: &mut R) -> CartesianPosition {
+        self.pos_from_index(self.random_index(rng))
+    }
+
+    /// Returns a position drawn uniformly at random among the positions whose element in `data` matches `pred`, or `None` if no element matches.
+    ///
+    /// Positions are first drawn by rejection sampling, up to `total_size` draws. If they all fail, the matching positions are collected to draw one of them, so that the call ends even when matching elements are rare.
+    ///
+    /// NO CHECK is done to verify that `data` has the same size as this grid.
+    pub fn random_position_where<D, F, R>(
+        &self,
+        rng: &mut R,
+        pred: F,
+        data: &GridData<C, D, CartesianGrid<C>>,
+    ) -> Option<CartesianPosition>
+    where
+        F: Fn(&D) -> bool,
+        R: Rng + ?Sized,
+    {
+        for _ in 0..self.total_size() {
+            let index = self.random_index(rng);
+            if pred(data.get(index)) {
+                return Some(self.pos_from_index(index));
+            }
+        }
+        let matching = data.indices_where(pred);
+        match matching.is_empty() {
+            true => None,
+            false => Some(self.pos_from_index(matching[rng.gen_range(0..matching.len())])),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use super::{
+    coordinates::{CartesianCoordinates, CartesianPosition},
+    grid::CartesianGrid,
+};
+
+#[cfg(feature = "bevy")]
+use bevy::ecs::component::Component;
+#[cfg(feature = "reflect")]
+use bevy::{ecs::reflect::ReflectComponent, reflect::Reflect};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Axis-aligned box of positions in a cartesian grid, from `min` included to `max` excluded on each axis.
+///
+/// A region where `min` is greater or equal to `max` on any axis is empty.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridRegion {
+    /// Lowest position of the region, included
+    pub min: CartesianPosition,
+    /// Highest position of the region, excluded
+    pub max: CartesianPosition,
+}
+
+impl GridRegion {
+    /// Creates a new [`GridRegion`] from `min` included to `max` excluded
+    pub fn new(min: CartesianPosition, max: CartesianPosition) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates a new [`GridRegion`] starting at `min` and of size `size`
+    pub fn from_size(min: CartesianPosition, size: (u32, u32, u32)) -> Self {
+        Self {
+            min,
+            max: CartesianPosition::new(
+                min.x.saturating_add(size.0),
+                min.y.saturating_add(size.1),
+                min.z.saturating_add(size.2),
+            ),
+        }
+    }
+
+    /// Returns a region covering the whole `grid`
+    pub fn of_grid<C: CartesianCoordinates>(grid: &CartesianGrid<C>) -> Self {
+        Self::from_size(CartesianPosition::new(0, 0, 0), grid.size())
+    }
+
+    /// Returns the size of the region on each axis, 0 on the axes where `min` is greater or equal to `max`
+    pub fn size(&self) -> (u32, u32, u32) {
+        (
+            self.max.x.saturating_sub(self.min.x),
+            self.max.y.saturating_sub(self.min.y),
+            self.max.z.saturating_sub(self.min.z),
+        )
+    }
+
+    /// Returns `true` if the region contains no position
+    pub fn is_empty(&self) -> bool {
+        self.volume() == 0
+    }
+
+    /// Returns the number of positions in the region, computed as a `u64` so that it can not overflow
+    pub fn volume(&self) -> u64 {
+        let (x, y, z) = self.size();
+        u64::from(x) * u64::from(y) * u64::from(z)
+    }
+
+    /// Returns `true` if `pos` is in the region
+    pub fn contains(&self, pos: &CartesianPosition) -> bool {
+        (self.min.x..self.max.x).contains(&pos.x)
+            && (self.min.y..self.max.y).contains(&pos.y)
+            && (self.min.z..self.max.z).contains(&pos.z)
+    }
+
+    /// Returns an iterator over all the positions of the region, by increasing z, then y, then x
+    pub fn iter_positions(&self) -> impl Iterator<Item = CartesianPosition> {
+        let (min, max) = (self.min, self.max);
+        (min.z..max.z).flat_map(move |z| {
+            (min.y..max.y)
+                .flat_map(move |y| (min.x..max.x).map(move |x| CartesianPosition::new(x, y, z)))
+        })
+    }
+
+    /// Returns the part of this region that lies within the bounds of `grid`
+    pub fn clamped_to<C: CartesianCoordinates>(&self, grid: &CartesianGrid<C>) -> GridRegion {
+        let (size_x, size_y, size_z) = grid.size();
+        GridRegion {
+            min: self.min,
+            max: CartesianPosition::new(
+                self.max.x.min(size_x),
+                self.max.y.min(size_y),
+                self.max.z.min(size_z),
+            ),
+        }
+    }
+}
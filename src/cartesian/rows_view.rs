@@ -0,0 +1,112 @@
+use crate::grid::GridData;
+
+use super::{
+    coordinates::{Cartesian2D, CartesianPosition, CartesianPosition2D},
+    grid::CartesianGrid,
+    region::GridRegion,
+};
+
+/// Mutable view over a range of contiguous rows of a 2d [`GridData`], see [`GridData::split_rows_mut`].
+///
+/// Positions used by the accessors are positions in the whole grid, not relative to the view.
+#[derive(Debug)]
+pub struct RowsViewMut<'a, D> {
+    data: &'a mut [D],
+    size_x: u32,
+    min_y: u32,
+}
+
+impl<'a, D> RowsViewMut<'a, D> {
+    /// Returns the y coordinate of the first row of this view
+    #[inline]
+    pub fn min_y(&self) -> u32 {
+        self.min_y
+    }
+
+    /// Returns the number of rows in this view
+    #[inline]
+    pub fn size_y(&self) -> u32 {
+        (self.data.len() / self.size_x as usize) as u32
+    }
+
+    /// Returns the [`GridRegion`] of the grid covered by this view
+    pub fn region(&self) -> GridRegion {
+        GridRegion::from_size(
+            CartesianPosition::new_xy(0, self.min_y),
+            (self.size_x, self.size_y(), 1),
+        )
+    }
+
+    /// Returns `true` if the position (`x`, `y`) of the grid is in this view
+    #[inline]
+    pub fn contains(&self, x: u32, y: u32) -> bool {
+        x < self.size_x && y >= self.min_y && y - self.min_y < self.size_y()
+    }
+
+    /// Returns a reference to the element at position (`x`, `y`) of the grid, or `None` if it is not in this view
+    pub fn get(&self, x: u32, y: u32) -> Option<&D> {
+        self.local_index(x, y).map(|index| &self.data[index])
+    }
+
+    /// Returns a mutable reference to the element at position (`x`, `y`) of the grid, or `None` if it is not in this view
+    pub fn get_mut(&mut self, x: u32, y: u32) -> Option<&mut D> {
+        self.local_index(x, y).map(|index| &mut self.data[index])
+    }
+
+    /// Returns the elements of this view as a contiguous slice, row by row
+    #[inline]
+    pub fn as_slice(&self) -> &[D] {
+        self.data
+    }
+
+    /// Returns the elements of this view as a contiguous mutable slice, row by row
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [D] {
+        self.data
+    }
+
+    /// Returns an iterator over the positions in the grid and mutable elements of this view, by increasing y, then x.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (CartesianPosition2D, &mut D)> {
+        let (size_x, min_y) = (self.size_x as usize, self.min_y);
+        self.data.iter_mut().enumerate().map(move |(index, d)| {
+            let pos =
+                CartesianPosition2D::new((index % size_x) as u32, min_y + (index / size_x) as u32);
+            (pos, d)
+        })
+    }
+
+    fn local_index(&self, x: u32, y: u32) -> Option<usize> {
+        self.contains(x, y)
+            .then(|| x as usize + (y - self.min_y) as usize * self.size_x as usize)
+    }
+}
+
+impl<D> GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>> {
+    /// Splits the grid into two disjoint mutable views: the rows before `at_y` (excluded) and the rows from `at_y` (included), mirroring [`slice::split_at_mut`].
+    ///
+    /// Panics if `at_y` is greater than the size of the grid on the Y axis.
+    pub fn split_rows_mut(&mut self, at_y: u32) -> (RowsViewMut<'_, D>, RowsViewMut<'_, D>) {
+        let size_x = self.grid().size_x();
+        assert!(
+            at_y <= self.grid().size_y(),
+            "at_y ({}) is greater than the grid size on the Y axis ({})",
+            at_y,
+            self.grid().size_y()
+        );
+        let (low, high) = self
+            .as_mut_slice()
+            .split_at_mut(at_y as usize * size_x as usize);
+        (
+            RowsViewMut {
+                data: low,
+                size_x,
+                min_y: 0,
+            },
+            RowsViewMut {
+                data: high,
+                size_x,
+                min_y: at_y,
+            },
+        )
+    }
+}
@@ -0,0 +1,146 @@
+//! Serialization of cartesian [`GridData`](crate::grid::GridData) as nested arrays following the shape of the grid, instead of a flat data buffer.
+//!
+//! Use with `#[serde(with = "ghx_grid::cartesian::serde_nested::rows")]` on a 2d [`GridData`](crate::grid::GridData) field to serialize its data as a list of rows, or with `#[serde(with = "ghx_grid::cartesian::serde_nested::layers")]` on a 3d [`GridData`](crate::grid::GridData) field to serialize its data as a list of layers of rows.
+
+use alloc::{vec, vec::Vec};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    direction::Axis,
+    error::GridError,
+    grid::{Grid, GridData},
+};
+
+use super::{
+    coordinates::{Cartesian2D, Cartesian3D, CartesianCoordinates},
+    grid::CartesianGrid,
+};
+
+/// Use with `#[serde(with = "ghx_grid::cartesian::serde_nested::rows")]` on a 2d [`GridData`] field to serialize its data as a list of rows: `rows[y][x]` is the element at position (`x`, `y`).
+pub mod rows {
+    use super::*;
+
+    #[derive(Serialize)]
+    #[serde(rename = "GridData")]
+    struct RowsGridDataRef<'a, D> {
+        grid: &'a CartesianGrid<Cartesian2D>,
+        rows: Vec<&'a [D]>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "GridData")]
+    struct RowsGridData<D> {
+        grid: CartesianGrid<Cartesian2D>,
+        rows: Vec<Vec<D>>,
+    }
+
+    /// Serializes `grid_data` as its grid and its data split in rows
+    pub fn serialize<D, S>(
+        grid_data: &GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        D: Serialize,
+        S: Serializer,
+    {
+        RowsGridDataRef {
+            grid: grid_data.grid(),
+            rows: grid_data.rows().collect(),
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserializes a [`GridData`] serialized with [`serialize`], validating the shape of the rows against the grid size
+    pub fn deserialize<'de, D, De>(
+        deserializer: De,
+    ) -> Result<GridData<Cartesian2D, D, CartesianGrid<Cartesian2D>>, De::Error>
+    where
+        D: Deserialize<'de>,
+        De: Deserializer<'de>,
+    {
+        let raw = RowsGridData::<D>::deserialize(deserializer)?;
+        let data = flatten_rows(&raw.grid, vec![raw.rows]).map_err(serde::de::Error::custom)?;
+        Ok(GridData::new(raw.grid, data))
+    }
+}
+
+/// Use with `#[serde(with = "ghx_grid::cartesian::serde_nested::layers")]` on a 3d [`GridData`] field to serialize its data as a list of layers of rows: `layers[z][y][x]` is the element at position (`x`, `y`, `z`).
+pub mod layers {
+    use super::*;
+
+    #[derive(Serialize)]
+    #[serde(rename = "GridData")]
+    struct LayersGridDataRef<'a, D> {
+        grid: &'a CartesianGrid<Cartesian3D>,
+        layers: Vec<Vec<&'a [D]>>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "GridData")]
+    struct LayersGridData<D> {
+        grid: CartesianGrid<Cartesian3D>,
+        layers: Vec<Vec<Vec<D>>>,
+    }
+
+    /// Serializes `grid_data` as its grid and its data split in layers of rows
+    pub fn serialize<D, S>(
+        grid_data: &GridData<Cartesian3D, D, CartesianGrid<Cartesian3D>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        D: Serialize,
+        S: Serializer,
+    {
+        let grid = grid_data.grid();
+        LayersGridDataRef {
+            grid,
+            layers: grid_data
+                .layers()
+                .map(|layer| layer.chunks_exact(grid.size_x() as usize).collect())
+                .collect(),
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserializes a [`GridData`] serialized with [`serialize`], validating the shape of the layers against the grid size
+    pub fn deserialize<'de, D, De>(
+        deserializer: De,
+    ) -> Result<GridData<Cartesian3D, D, CartesianGrid<Cartesian3D>>, De::Error>
+    where
+        D: Deserialize<'de>,
+        De: Deserializer<'de>,
+    {
+        let raw = LayersGridData::<D>::deserialize(deserializer)?;
+        let data = flatten_rows(&raw.grid, raw.layers).map_err(serde::de::Error::custom)?;
+        Ok(GridData::new(raw.grid, data))
+    }
+}
+
+/// Flattens `layers` into a data buffer, checking that each layer, row and element count matches the grid size
+fn flatten_rows<C: CartesianCoordinates, D>(
+    grid: &CartesianGrid<C>,
+    layers: Vec<Vec<Vec<D>>>,
+) -> Result<Vec<D>, GridError> {
+    let (size_x, size_y, size_z) = grid.size();
+    check_len(Axis::Z, size_z, layers.len())?;
+    let mut data = Vec::with_capacity(grid.total_size());
+    for layer in layers {
+        check_len(Axis::Y, size_y, layer.len())?;
+        for row in layer {
+            check_len(Axis::X, size_x, row.len())?;
+            data.extend(row);
+        }
+    }
+    Ok(data)
+}
+
+fn check_len(axis: Axis, expected: u32, got: usize) -> Result<(), GridError> {
+    if got != expected as usize {
+        return Err(GridError::ShapeMismatch {
+            axis,
+            expected: expected as usize,
+            got,
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! The derived serde representation of a [`CartesianGrid`](crate::cartesian::grid::CartesianGrid) mirrors its fields and has no version: saves made with a different layout can fail to load or load with a different meaning. [`VersionedCartesianGrid`](crate::cartesian::serde_versioned::VersionedCartesianGrid) is a representation tagged with its layout version, which older saves can be migrated from.
+//!
+//! Use with `#[serde(with = "ghx_grid::cartesian::serde_versioned")]` on a [`CartesianGrid`](crate::cartesian::grid::CartesianGrid) field to serialize it in the current version and deserialize it from any known version.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::GridError;
+
+use super::{coordinates::CartesianCoordinates, grid::CartesianGrid};
+
+/// Serialized layouts of a [`CartesianGrid`], tagged with their version.
+///
+/// The current version is [`VersionedCartesianGrid::CURRENT_VERSION`]. Versions are only ever added: a save made with any version listed here can be loaded with [`CartesianGrid::from_versioned`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VersionedCartesianGrid<C> {
+    /// Version 1: sizes, looping flags and coordinate system of the grid. Cached values are not serialized and are recomputed when loading.
+    V1 {
+        /// Size of the grid on each axis
+        size: (u32, u32, u32),
+        /// Looping flag of the grid on each axis
+        looping: (bool, bool, bool),
+        /// Coordinate system of the grid
+        coord_system: C,
+    },
+}
+
+impl<C> VersionedCartesianGrid<C> {
+    /// Version used by [`CartesianGrid::to_versioned`]
+    pub const CURRENT_VERSION: u32 = 1;
+
+    /// Returns the version of this layout
+    pub fn version(&self) -> u32 {
+        match self {
+            VersionedCartesianGrid::V1 { .. } => 1,
+        }
+    }
+}
+
+impl<C: CartesianCoordinates> CartesianGrid<C> {
+    /// Returns the representation of this grid in the current version, [`VersionedCartesianGrid::CURRENT_VERSION`]
+    pub fn to_versioned(&self) -> VersionedCartesianGrid<C> {
+        VersionedCartesianGrid::V1 {
+            size: self.size(),
+            looping: self.looping(),
+            coord_system: self.coord_system().clone(),
+        }
+    }
+
+    /// Creates a grid from its representation in any version, migrating it to the current one.
+    ///
+    /// Returns [`GridError::ZeroDimension`] or [`GridError::DimensionOverflow`] if the stored sizes are not valid, see [`CartesianGrid::try_new`].
+    pub fn from_versioned(versioned: VersionedCartesianGrid<C>) -> Result<Self, GridError> {
+        match versioned {
+            VersionedCartesianGrid::V1 {
+                size,
+                looping,
+                coord_system,
+            } => CartesianGrid::try_new(
+                size.0,
+                size.1,
+                size.2,
+                looping.0,
+                looping.1,
+                looping.2,
+                coord_system,
+            ),
+        }
+    }
+}
+
+/// Serializes `grid` in the current version, see [`CartesianGrid::to_versioned`]
+pub fn serialize<C, S>(grid: &CartesianGrid<C>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    C: CartesianCoordinates + Serialize,
+    S: Serializer,
+{
+    grid.to_versioned().serialize(serializer)
+}
+
+/// Deserializes a [`CartesianGrid`] serialized in any version, see [`CartesianGrid::from_versioned`]
+pub fn deserialize<'de, C, De>(deserializer: De) -> Result<CartesianGrid<C>, De::Error>
+where
+    C: CartesianCoordinates + Deserialize<'de>,
+    De: Deserializer<'de>,
+{
+    let versioned = VersionedCartesianGrid::<C>::deserialize(deserializer)?;
+    CartesianGrid::from_versioned(versioned).map_err(serde::de::Error::custom)
+}
@@ -1,4 +1,7 @@
-use crate::direction::DirectionTrait;
+use crate::{
+    cartesian::coordinates::GridDelta,
+    direction::{DirectionIndex, DirectionTrait},
+};
 
 /// Represents a coordinate system
 pub trait CoordinateSystem: Default + Clone + Sync + Send + 'static {
@@ -6,8 +9,30 @@ pub trait CoordinateSystem: Default + Clone + Sync + Send + 'static {
     type Direction: DirectionTrait;
 
     /// Returns the [`DirectionTrait`] used in this coordinate system
-    fn directions(&self) -> &'static [Self::Direction];
+    ///
+    /// The slice is borrowed from `self` so that coordinate systems defined at runtime, such as [`crate::cartesian::custom::CustomCoordinates`], can own their directions. The directions of the coordinate systems of this crate are also available as `'static` constants, such as [`crate::cartesian::coordinates::CARTESIAN_2D_DIRECTIONS`].
+    fn directions(&self) -> &[Self::Direction];
 
     /// Returns the total count of directions
     fn directions_count(&self) -> usize;
+
+    /// Returns the [`GridDelta`] of a move in each direction of this coordinate system, in the order of [`CoordinateSystem::directions`].
+    ///
+    /// Returns `None` by default, for coordinate systems where a move in a direction does not always have the same delta, such as staggered coordinates. Cartesian coordinate systems return their [`crate::cartesian::coordinates::CartesianCoordinates::deltas`].
+    fn direction_deltas(&self) -> Option<&[GridDelta]> {
+        None
+    }
+
+    /// Returns `true` if `direction` is one of the directions of this coordinate system, compared by [`DirectionIndex`]
+    fn contains_direction(&self, direction: Self::Direction) -> bool {
+        let index: DirectionIndex = direction.into();
+        self.directions()
+            .iter()
+            .any(|dir| Into::<DirectionIndex>::into(*dir) == index)
+    }
+
+    /// Returns the index of the direction opposite to the direction at `dir_index` in [`CoordinateSystem::directions`]
+    fn opposite_index(&self, dir_index: DirectionIndex) -> DirectionIndex {
+        self.directions()[dir_index].opposite().into()
+    }
 }
@@ -1,10 +1,18 @@
 // TODO See if std::ops::index can be used here
 
+use alloc::string::ToString;
+use core::{fmt, str::FromStr};
+
+use crate::{cartesian::coordinates::GridDelta, error::GridError};
+
 #[cfg(feature = "bevy")]
 use bevy::ecs::component::Component;
 #[cfg(feature = "reflect")]
 use bevy::{ecs::reflect::ReflectComponent, reflect::Reflect};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 // TODO Might reduce this to a u8
 /// Index of a direction
 pub type DirectionIndex = usize;
@@ -16,12 +24,28 @@ pub trait DirectionTrait: Into<DirectionIndex> + Copy {
     fn opposite(&self) -> Self;
     /// Right-handed.
     fn rotation_basis(&self) -> &'static [Self];
+    /// Returns the [`Axis`] this direction is on
+    fn axis(&self) -> Axis;
+}
+
+/// Represents an axis of a coordinate system
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Axis {
+    /// X axis
+    X = 0,
+    /// Y axis
+    Y = 1,
+    /// Z axis
+    Z = 2,
 }
 
 /// Represents an oriented axis of a coordinate system
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy", derive(Component))]
 #[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Direction {
     /// X+ axis
     #[default]
@@ -61,44 +85,181 @@ impl DirectionTrait for Direction {
             Direction::ZBackward => Z_NEG_AXIS,
         }
     }
+
+    fn axis(&self) -> Axis {
+        match self {
+            Direction::XForward | Direction::XBackward => Axis::X,
+            Direction::YForward | Direction::YBackward => Axis::Y,
+            Direction::ZForward | Direction::ZBackward => Axis::Z,
+        }
+    }
 }
+impl Direction {
+    /// Returns `true` if this direction points towards the positive side of its axis
+    pub fn is_positive(&self) -> bool {
+        matches!(
+            self,
+            Direction::XForward | Direction::YForward | Direction::ZForward
+        )
+    }
+
+    /// Returns the unit [`GridDelta`] of a move in this direction
+    pub fn delta(&self) -> GridDelta {
+        match self {
+            Direction::XForward => GridDelta::new(1, 0, 0),
+            Direction::XBackward => GridDelta::new(-1, 0, 0),
+            Direction::YForward => GridDelta::new(0, 1, 0),
+            Direction::YBackward => GridDelta::new(0, -1, 0),
+            Direction::ZForward => GridDelta::new(0, 0, 1),
+            Direction::ZBackward => GridDelta::new(0, 0, -1),
+        }
+    }
+
+    /// Returns the direction after a quarter turn to the left (counter-clockwise) in the XY plane, following the right-handed rotation around Z+: `XForward` gives `YForward`.
+    ///
+    /// Vertical directions are returned unchanged.
+    pub fn turn_left(&self) -> Direction {
+        self.turn_in_xy_plane(1)
+    }
+
+    /// Returns the direction after a quarter turn to the right (clockwise) in the XY plane: `YForward` gives `XForward`.
+    ///
+    /// Vertical directions are returned unchanged.
+    pub fn turn_right(&self) -> Direction {
+        self.turn_in_xy_plane(3)
+    }
+
+    /// Returns the opposite direction, see [`DirectionTrait::opposite`].
+    pub fn turn_around(&self) -> Direction {
+        self.opposite()
+    }
+
+    /// Rotates an horizontal direction by `quarter_turns` counter-clockwise quarter turns around Z+
+    fn turn_in_xy_plane(&self, quarter_turns: usize) -> Direction {
+        match Z_POS_AXIS.iter().position(|dir| dir == self) {
+            Some(i) => Z_POS_AXIS[(i + quarter_turns) % Z_POS_AXIS.len()],
+            None => *self,
+        }
+    }
+
+    /// Returns an iterator over all the directions, in the order of their index
+    pub fn all() -> impl Iterator<Item = Direction> {
+        HORIZONTAL_DIRECTIONS
+            .iter()
+            .chain(VERTICAL_DIRECTIONS)
+            .copied()
+    }
+
+    /// Returns an iterator over the four horizontal directions, in the order of their index
+    pub fn all_2d() -> impl Iterator<Item = Direction> {
+        HORIZONTAL_DIRECTIONS.iter().copied()
+    }
+
+    /// Returns `true` if this direction is along the Z axis
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, Direction::ZForward | Direction::ZBackward)
+    }
+}
+
+/// Horizontal directions, in the XY plane
+pub const HORIZONTAL_DIRECTIONS: &[Direction] = &[
+    Direction::XForward,
+    Direction::YForward,
+    Direction::XBackward,
+    Direction::YBackward,
+];
+
+/// Number of [`Direction`] variants: the maximum number of directions of a cartesian coordinate system
+pub const MAX_DIRECTIONS: usize = 6;
+
+/// Vertical directions, along the Z axis
+pub const VERTICAL_DIRECTIONS: &[Direction] = &[Direction::ZForward, Direction::ZBackward];
 impl From<Direction> for usize {
     fn from(item: Direction) -> Self {
         item as Self
     }
 }
 
-pub(crate) const X_POS_AXIS: &'static [Direction] = &[
+/// Since [`DirectionIndex`] is a `usize`, this also converts a [`DirectionIndex`] back to a [`Direction`].
+impl TryFrom<usize> for Direction {
+    type Error = GridError;
+
+    fn try_from(index: usize) -> Result<Self, Self::Error> {
+        Direction::all()
+            .nth(index)
+            .ok_or(GridError::InvalidDirectionIndex { index })
+    }
+}
+
+/// Names accepted by the [`FromStr`] implementation of [`Direction`], the first name of each direction being the one used by its [`fmt::Display`] implementation.
+const DIRECTION_NAMES: &[(Direction, &[&str])] = &[
+    (Direction::XForward, &["x+", "+x", "xforward"]),
+    (Direction::YForward, &["y+", "+y", "yforward"]),
+    (Direction::XBackward, &["x-", "-x", "xbackward"]),
+    (Direction::YBackward, &["y-", "-y", "ybackward"]),
+    (Direction::ZForward, &["z+", "+z", "zforward", "up"]),
+    (Direction::ZBackward, &["z-", "-z", "zbackward", "down"]),
+];
+
+/// Writes the canonical name of the direction: `x+`, `y+`, `x-`, `y-`, `z+` or `z-`.
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(DIRECTION_NAMES[*self as usize].1[0])
+    }
+}
+
+/// Parses a direction from its name, ignoring case. Accepted names for each direction are:
+/// - its canonical name, as written by its [`fmt::Display`] implementation: `x+`, `y+`, `x-`, `y-`, `z+`, `z-`
+/// - the sign before the axis: `+x`, `-y`, ...
+/// - its variant name: `XForward`, `YBackward`, ...
+/// - `up` for [`Direction::ZForward`] and `down` for [`Direction::ZBackward`], since Z is the vertical axis (see [`Direction::is_vertical`])
+///
+/// Returns [`GridError::UnknownDirectionName`] for any other name.
+impl FromStr for Direction {
+    type Err = GridError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        DIRECTION_NAMES
+            .iter()
+            .find(|(_, names)| names.iter().any(|n| n.eq_ignore_ascii_case(name)))
+            .map(|(direction, _)| *direction)
+            .ok_or_else(|| GridError::UnknownDirectionName {
+                name: name.to_string(),
+            })
+    }
+}
+
+pub(crate) const X_POS_AXIS: &[Direction] = &[
     Direction::YForward,
     Direction::ZForward,
     Direction::YBackward,
     Direction::ZBackward,
 ];
-pub(crate) const X_NEG_AXIS: &'static [Direction] = &[
+pub(crate) const X_NEG_AXIS: &[Direction] = &[
     Direction::ZForward,
     Direction::YForward,
     Direction::ZBackward,
     Direction::YBackward,
 ];
-pub(crate) const Y_POS_AXIS: &'static [Direction] = &[
+pub(crate) const Y_POS_AXIS: &[Direction] = &[
     Direction::ZForward,
     Direction::XForward,
     Direction::ZBackward,
     Direction::XBackward,
 ];
-pub(crate) const Y_NEG_AXIS: &'static [Direction] = &[
+pub(crate) const Y_NEG_AXIS: &[Direction] = &[
     Direction::XForward,
     Direction::ZForward,
     Direction::XBackward,
     Direction::ZBackward,
 ];
-pub(crate) const Z_POS_AXIS: &'static [Direction] = &[
+pub(crate) const Z_POS_AXIS: &[Direction] = &[
     Direction::XForward,
     Direction::YForward,
     Direction::XBackward,
     Direction::YBackward,
 ];
-pub(crate) const Z_NEG_AXIS: &'static [Direction] = &[
+pub(crate) const Z_NEG_AXIS: &[Direction] = &[
     Direction::YForward,
     Direction::XForward,
     Direction::YBackward,
@@ -0,0 +1,44 @@
+use bevy::ecs::{bundle::Bundle, entity::Entity, system::Commands};
+
+use crate::{
+    coordinate_system::CoordinateSystem,
+    grid::{Grid, GridData},
+};
+
+/// Bundle holding a grid and its data, to spawn them together on an entity.
+///
+/// The grid definition is only stored inside the [`GridData`], so that it can not get out of sync with the data: systems access it through [`GridData::grid`].
+///
+/// [`GridData`] is a [`Component`](bevy::ecs::component::Component) when `C`, `D` and `G` are `Send + Sync + 'static`. Its `PhantomData<C>` marker holds no data: it only requires the coordinate system type to satisfy these bounds, which all the coordinate systems of this crate do.
+#[derive(Bundle)]
+pub struct GridBundle<C, D, G>
+where
+    C: CoordinateSystem + Send + Sync + 'static,
+    D: Send + Sync + 'static,
+    G: Grid<C> + Send + Sync + 'static,
+{
+    /// Grid definition and content
+    pub data: GridData<C, D, G>,
+}
+
+impl<C, D, G> GridBundle<C, D, G>
+where
+    C: CoordinateSystem + Send + Sync + 'static,
+    D: Send + Sync + 'static,
+    G: Grid<C> + Send + Sync + 'static,
+{
+    /// Creates a new bundle from `grid_data`
+    pub fn new(grid_data: GridData<C, D, G>) -> Self {
+        Self { data: grid_data }
+    }
+}
+
+/// Spawns a new entity holding `grid_data`, see [`GridBundle`]
+pub fn spawn_grid<C, D, G>(commands: &mut Commands, grid_data: GridData<C, D, G>) -> Entity
+where
+    C: CoordinateSystem + Send + Sync + 'static,
+    D: Send + Sync + 'static,
+    G: Grid<C> + Send + Sync + 'static,
+{
+    commands.spawn(GridBundle::new(grid_data)).id()
+}
@@ -0,0 +1,161 @@
+use alloc::string::String;
+use core::fmt;
+
+use crate::direction::Axis;
+
+/// Errors that can be returned by grid operations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// The length of a data buffer does not match the total size of its grid
+    LengthMismatch {
+        /// Expected length: the total size of the grid
+        expected: usize,
+        /// Actual length of the data buffer
+        got: usize,
+    },
+    /// A run of a run-length encoded buffer has a length of 0
+    EmptyRun {
+        /// Index of the empty run
+        run_index: usize,
+    },
+    /// The total size of a grid does not fit in its index type
+    DimensionOverflow {
+        /// Requested size of the grid on each axis
+        size: (u32, u32, u32),
+    },
+    /// Two grids that should be aligned do not have the same total size
+    DimensionMismatch {
+        /// Total size of the first grid
+        expected: usize,
+        /// Total size of the second grid
+        got: usize,
+    },
+    /// Two grids that should be aligned have the same total size but are not equal (e.g. a 2x3 and a 3x2 grid)
+    GridMismatch,
+    /// A direction index does not match any direction
+    InvalidDirectionIndex {
+        /// The invalid index
+        index: usize,
+    },
+    /// An index is not a valid index of a grid
+    IndexOutOfBounds {
+        /// The invalid index
+        index: usize,
+        /// Total size of the grid
+        size: usize,
+    },
+    /// A position is not a valid position of a grid
+    PositionOutOfBounds {
+        /// The invalid position
+        position: (u32, u32, u32),
+        /// Size of the grid on each axis
+        size: (u32, u32, u32),
+    },
+    /// A row does not have the same length as the first row
+    RaggedRows {
+        /// Index of the first row with a different length
+        row: usize,
+        /// Length of the first row
+        expected: usize,
+        /// Length of the row
+        got: usize,
+    },
+    /// Nested data does not have the size of its grid along an axis
+    ShapeMismatch {
+        /// Axis along which the sizes differ
+        axis: Axis,
+        /// Size of the grid along this axis
+        expected: usize,
+        /// Number of elements along this axis
+        got: usize,
+    },
+    /// A name does not match any direction
+    UnknownDirectionName {
+        /// The unknown name
+        name: String,
+    },
+    /// A grid looping on an axis requires an even size on this axis
+    OddLoopingSize {
+        /// Looping axis
+        axis: Axis,
+        /// Requested size of the grid on this axis
+        size: u32,
+    },
+    /// A grid has a size of 0 on at least one axis
+    ZeroDimension {
+        /// Requested size of the grid on each axis
+        size: (u32, u32, u32),
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::LengthMismatch { expected, got } => write!(
+                f,
+                "data length ({}) does not match the grid total size ({})",
+                got, expected
+            ),
+            GridError::EmptyRun { run_index } => {
+                write!(f, "run {} has a length of 0", run_index)
+            }
+            GridError::DimensionOverflow { size } => write!(
+                f,
+                "grid size {}x{}x{} overflows the grid index type",
+                size.0, size.1, size.2
+            ),
+            GridError::DimensionMismatch { expected, got } => write!(
+                f,
+                "grid total size ({}) does not match the expected total size ({})",
+                got, expected
+            ),
+            GridError::GridMismatch => write!(
+                f,
+                "grids have the same total size but different definitions"
+            ),
+            GridError::InvalidDirectionIndex { index } => {
+                write!(f, "{} is not a valid direction index", index)
+            }
+            GridError::IndexOutOfBounds { index, size } => write!(
+                f,
+                "index {} is out of bounds for a grid of total size {}",
+                index, size
+            ),
+            GridError::PositionOutOfBounds { position, size } => write!(
+                f,
+                "position ({}, {}, {}) is out of bounds for a grid of size {}x{}x{}",
+                position.0, position.1, position.2, size.0, size.1, size.2
+            ),
+            GridError::RaggedRows { row, expected, got } => write!(
+                f,
+                "row {} has a length of {} but the first row has a length of {}",
+                row, got, expected
+            ),
+            GridError::ShapeMismatch {
+                axis,
+                expected,
+                got,
+            } => write!(
+                f,
+                "{} elements along the {:?} axis do not match the grid size ({})",
+                got, axis, expected
+            ),
+            GridError::UnknownDirectionName { name } => {
+                write!(f, "\"{}\" is not a valid direction name", name)
+            }
+            GridError::OddLoopingSize { axis, size } => write!(
+                f,
+                "looping on the {:?} axis requires an even size, but the size is {}",
+                axis, size
+            ),
+            GridError::ZeroDimension { size } => write!(
+                f,
+                "grid size {}x{}x{} has a zero dimension",
+                size.0, size.1, size.2
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GridError {}
@@ -1,10 +1,21 @@
-use std::{
+use alloc::{
+    collections::{BTreeMap, BinaryHeap, VecDeque},
+    vec,
+    vec::Vec,
+};
+use core::{
+    cmp::Reverse,
     fmt::Debug,
+    hash::{Hash, Hasher},
     marker::PhantomData,
+    ops::{Index, IndexMut},
     slice::{Iter, IterMut},
 };
 
-use crate::coordinate_system::CoordinateSystem;
+use crate::{
+    coordinate_system::CoordinateSystem, direction::DirectionIndex,
+    grid::bit_grid_data::BitGridData,
+};
 
 #[cfg(feature = "bevy")]
 use bevy::ecs::component::Component;
@@ -12,10 +23,42 @@ use bevy::ecs::component::Component;
 use bevy::{ecs::reflect::ReflectComponent, reflect::Reflect};
 
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Bit-packed storage for boolean grids
+pub mod bit_grid_data;
+
+/// Grid wrapper caching the neighbours of its elements
+pub mod precomputed;
+
+/// Grid data borrowing its grid definition
+pub mod grid_data_ref;
+
+/// Run-length encoded serde representation of [`GridData`]
+#[cfg(feature = "serde-rle")]
+pub mod serde_rle;
+
+/// JSON persistence of [`GridData`]
+#[cfg(feature = "json")]
+pub mod json;
 
-// TODO Might reduce this to u32 by default, with a feature for u64
-/// Index of a grid element
+use crate::error::GridError;
+
+/// Index of a grid element.
+///
+/// `u32` by default, `usize` when the `large-grids` feature is enabled.
+#[cfg(not(feature = "large-grids"))]
+pub type GridIndex = u32;
+/// Index of a grid element.
+///
+/// `u32` by default, `usize` when the `large-grids` feature is enabled.
+#[cfg(feature = "large-grids")]
 pub type GridIndex = usize;
 
 /// Generic trait to represent a grid
@@ -32,26 +75,125 @@ pub trait Grid<C: CoordinateSystem>: Clone {
     /// Returns the total size of the grid
     fn total_size(&self) -> usize;
 
+    /// Returns the directions of the coordinate system of this grid, see [`CoordinateSystem::directions`]
+    #[inline]
+    fn directions(&self) -> &[C::Direction] {
+        self.coord_system().directions()
+    }
+
+    /// Returns the index of `direction`: its index in [`Grid::directions`] and in the buffers filled by [`Grid::get_neighbours_in_all_directions`]
+    #[inline]
+    fn direction_index(&self, direction: C::Direction) -> DirectionIndex {
+        direction.into()
+    }
+
+    /// Returns the size of a buffer holding one value per element and per direction: `total_size() * directions_count()`. See [`Grid::directional_index`] for its layout.
+    ///
+    /// Panics if the size overflows a `usize`.
+    #[inline]
+    fn total_directional_size(&self) -> usize {
+        self.total_size()
+            .checked_mul(self.directions_count())
+            .expect("total directional size overflows a usize")
+    }
+
+    /// Returns the offset of the value of the element at `cell` in the direction `dir_index` in a buffer of size [`Grid::total_directional_size`]: the values of the directions of an element are contiguous, in direction index order.
+    ///
+    /// NO CHECK is done to verify that the given index and direction index are valid for this grid.
+    #[inline]
+    fn directional_index(&self, cell: GridIndex, dir_index: DirectionIndex) -> usize {
+        cell as usize * self.directions_count() + dir_index
+    }
+
     /// Will retrieve the next element's indexes in each direction.
     ///
-    /// - `neighbours_buffer` should be allocated by the caller and its size should be >= to `directions.len()`
+    /// - `neighbours_buffer` should be allocated by the caller and its size should be >= to `directions.len()`. See [`Grid::neighbour_buffer`] and [`Grid::reset_neighbour_buffer`].
     fn get_neighbours_in_all_directions(
         &self,
         grid_index: GridIndex,
         neighbours_buffer: &mut Vec<Option<GridIndex>>,
     );
 
+    /// Returns the index of the next element in `direction` from the element at `index`, or `None` if there is no such element in the grid.
+    fn neighbour(&self, index: GridIndex, direction: C::Direction) -> Option<GridIndex> {
+        let mut neighbours_buffer = self.neighbour_buffer();
+        self.get_neighbours_in_all_directions(index, &mut neighbours_buffer);
+        neighbours_buffer[direction.into()]
+    }
+
+    /// Returns the number of neighbours of the element at `index` that are in the grid.
+    ///
+    /// This can be lower than [`Grid::directions_count`] for elements at the border of the grid.
+    fn in_bounds_neighbour_count(&self, index: GridIndex) -> usize {
+        let mut neighbours_buffer = self.neighbour_buffer();
+        self.get_neighbours_in_all_directions(index, &mut neighbours_buffer);
+        neighbours_buffer.iter().filter(|n| n.is_some()).count()
+    }
+
+    /// Returns a new buffer, filled with `None`, with the correct size to be used with [`Grid::get_neighbours_in_all_directions`]
+    fn neighbour_buffer(&self) -> Vec<Option<GridIndex>> {
+        vec![None; self.directions_count()]
+    }
+
+    /// Returns a new buffer filled with the neighbours of the element at `index`, see [`Grid::get_neighbours_in_all_directions`].
+    ///
+    /// Prefer [`Grid::get_neighbours_in_all_directions`] with a reused buffer when querying many elements.
+    fn collect_neighbours(&self, index: GridIndex) -> Vec<Option<GridIndex>> {
+        let mut neighbours_buffer = self.neighbour_buffer();
+        self.get_neighbours_in_all_directions(index, &mut neighbours_buffer);
+        neighbours_buffer
+    }
+
+    /// Resizes `neighbours_buffer` to the correct size to be used with [`Grid::get_neighbours_in_all_directions`] and fills it with `None`.
+    ///
+    /// Existing allocation is kept if possible.
+    fn reset_neighbour_buffer(&self, neighbours_buffer: &mut Vec<Option<GridIndex>>) {
+        neighbours_buffer.clear();
+        neighbours_buffer.resize(self.directions_count(), None);
+    }
+
     /// Converts a position into an index
     fn index_from_pos(&self, pos: &Self::Position) -> GridIndex;
     /// Converts an index into an position
     fn pos_from_index(&self, index: GridIndex) -> Self::Position;
+
+    /// Returns `true` if `index` is a valid index for this grid
+    #[inline]
+    fn is_valid_index(&self, index: GridIndex) -> bool {
+        (index as usize) < self.total_size()
+    }
+
+    /// Returns `true` if `pos` is a valid position for this grid.
+    ///
+    /// By default, only checks that the index of the position is a valid index. Grids whose positions can map to a valid index while being out of bounds should override it to check the position itself.
+    #[inline]
+    fn is_valid_pos(&self, pos: &Self::Position) -> bool {
+        self.is_valid_index(self.index_from_pos(pos))
+    }
+
+    /// Converts a position into an index, returning `None` if `pos` is not a valid position for this grid (see [`Grid::is_valid_pos`]).
+    ///
+    /// Unlike [`Grid::index_from_pos`], this can be used safely with positions that may be out of the grid.
+    #[inline]
+    fn try_index_from_pos(&self, pos: &Self::Position) -> Option<GridIndex> {
+        match self.is_valid_pos(pos) {
+            true => Some(self.index_from_pos(pos)),
+            false => None,
+        }
+    }
+
+    /// Returns an iterator over the positions of all the elements of the grid, in the order of their index
+    fn iter_positions(&self) -> impl Iterator<Item = Self::Position> + '_ {
+        (0..self.total_size() as GridIndex).map(|index| self.pos_from_index(index))
+    }
 }
 
 /// Holds a [`Grid`] and generic data in a linear buffer that can be accessed through the grid definition to represent the grid content.
-#[derive(Clone)]
-#[cfg_attr(feature = "bevy", derive(Component, Default))]
+///
+/// The length of the data buffer is always equal to the `total_size` of the grid.
+#[cfg_attr(feature = "bevy", derive(Component))]
 #[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct GridData<C, D, G>
 where
     C: CoordinateSystem,
@@ -70,6 +212,8 @@ where
     G: Grid<C>,
 {
     /// Prefer using `default_grid_data` or `new_grid_data` directly on an existing grid definition to create a `GridData` with a correct data Vec.
+    ///
+    /// `data.len()` must be equal to `grid.total_size()`, NO CHECK is done to verify it: accesses may panic later if it is not. See [`GridData::try_from_vec`] for a checked version.
     #[inline]
     pub fn new(grid: G, data: Vec<D>) -> Self {
         Self {
@@ -79,6 +223,31 @@ where
         }
     }
 
+    /// Creates a `GridData` from a grid definition and a data buffer, or returns [`GridError::LengthMismatch`] if `data.len()` is not equal to `grid.total_size()`.
+    pub fn try_from_vec(grid: G, data: Vec<D>) -> Result<Self, GridError> {
+        if data.len() != grid.total_size() {
+            return Err(GridError::LengthMismatch {
+                expected: grid.total_size(),
+                got: data.len(),
+            });
+        }
+        Ok(Self::new(grid, data))
+    }
+
+    /// Decomposes this `GridData` into its grid definition and its data buffer.
+    #[inline]
+    pub fn into_raw(self) -> (G, Vec<D>) {
+        (self.grid, self.data)
+    }
+
+    /// Returns mutable references to the grid definition and to the data buffer.
+    ///
+    /// Callers are responsible for keeping the data length equal to the grid `total_size`.
+    #[inline]
+    pub(crate) fn grid_and_data_mut(&mut self) -> (&mut G, &mut Vec<D>) {
+        (&mut self.grid, &mut self.data)
+    }
+
     /// Returns a reference to the `GridDefinition` this is based on
     #[inline]
     pub fn grid(&self) -> &G {
@@ -90,7 +259,7 @@ where
     /// NO CHECK is done to verify that the given index is a valid index for this grid.
     #[inline]
     pub fn set_raw(&mut self, index: GridIndex, value: D) {
-        self.data[index] = value;
+        self.data[index as usize] = value;
     }
 
     /// Sets the value of the element at `index_ref` in the grid.
@@ -98,7 +267,7 @@ where
     /// NO CHECK is done to verify that the given index is a valid index for this grid.
     #[inline]
     pub fn set<N: NodeRef<C, G>>(&mut self, index_ref: N, value: D) {
-        self.data[index_ref.to_index(&self.grid)] = value;
+        self.data[index_ref.to_index(&self.grid) as usize] = value;
     }
 
     /// Returns a reference to the element at this index.
@@ -106,7 +275,7 @@ where
     /// NO CHECK is done to verify that the given index is a valid index for this grid.
     #[inline]
     pub fn get(&self, index: GridIndex) -> &D {
-        &self.data[index]
+        &self.data[index as usize]
     }
 
     /// Returns a mutable reference to the element at this index.
@@ -114,7 +283,93 @@ where
     /// NO CHECK is done to verify that the given index is a valid index for this grid.
     #[inline]
     pub fn get_mut(&mut self, index: GridIndex) -> &mut D {
-        &mut self.data[index]
+        &mut self.data[index as usize]
+    }
+
+    /// Swaps the values of the elements at indexes `a` and `b`.
+    ///
+    /// Panics if `a` or `b` are not valid indexes for this grid.
+    #[inline]
+    pub fn swap(&mut self, a: GridIndex, b: GridIndex) {
+        self.data.swap(a as usize, b as usize);
+    }
+
+    /// Same as [`GridData::set`] but returns an error instead of panicking or writing to the wrong element if `index_ref` does not reference a valid element of the grid.
+    #[inline]
+    pub fn try_set<N: NodeRef<C, G>>(&mut self, index_ref: N, value: D) -> Result<(), GridError> {
+        let index = index_ref.try_to_index(&self.grid)?;
+        self.data[index as usize] = value;
+        Ok(())
+    }
+
+    /// Calls `f` with a mutable reference to the element at `index`.
+    ///
+    /// NO CHECK is done to verify that the given index is a valid index for this grid.
+    #[inline]
+    pub fn apply_at<F: FnOnce(&mut D)>(&mut self, index: GridIndex, f: F) {
+        f(&mut self.data[index as usize]);
+    }
+
+    /// Same as [`GridData::apply_at`] but returns `false` without calling `f` if `index` is not a valid index for this grid.
+    #[inline]
+    pub fn try_apply_at<F: FnOnce(&mut D)>(&mut self, index: GridIndex, f: F) -> bool {
+        match self.data.get_mut(index as usize) {
+            Some(d) => {
+                f(d);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Calls `f` with a mutable reference to the element at `index` and references to the values of its neighbours, and returns its result.
+    ///
+    /// Neighbours are given in the order of [`Grid::get_neighbours_in_all_directions`], with `None` for the ones outside of the grid. An element which is its own neighbour, on a looping axis of size 1, is also given as `None`.
+    ///
+    /// Panics if `index` is not a valid index for this grid.
+    pub fn with_neighbours<R, F: FnOnce(&mut D, &[Option<&D>]) -> R>(
+        &mut self,
+        index: GridIndex,
+        f: F,
+    ) -> R {
+        let neighbours = self.grid.collect_neighbours(index);
+        let (before, rest) = self.data.split_at_mut(index as usize);
+        let (center, after) = rest
+            .split_first_mut()
+            .expect("index should be a valid index for this grid");
+        let values: Vec<Option<&D>> = neighbours
+            .iter()
+            .map(|neighbour| match neighbour {
+                Some(n) if *n < index => Some(&before[*n as usize]),
+                Some(n) if *n > index => Some(&after[(*n - index - 1) as usize]),
+                _ => None,
+            })
+            .collect();
+        f(center, &values)
+    }
+
+    /// Returns the number of elements the data buffer can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    /// Shrinks the capacity of the data buffer as much as possible.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+    }
+
+    /// Returns the data buffer as a slice. Its length is equal to the `total_size` of the grid.
+    #[inline]
+    pub fn as_slice(&self) -> &[D] {
+        &self.data
+    }
+
+    /// Returns the data buffer as a mutable slice. Its length is equal to the `total_size` of the grid.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [D] {
+        &mut self.data
     }
 
     /// Returns an iterator over all the elements.
@@ -129,26 +384,712 @@ where
         self.data.iter_mut()
     }
 
+    /// Returns an iterator over the indexes of all the elements and mutable references to them, in index order.
+    #[inline]
+    pub fn iter_mut_indexed(&mut self) -> impl Iterator<Item = (GridIndex, &mut D)> {
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(|(index, d)| (index as GridIndex, d))
+    }
+
+    /// Splits the data buffer into two disjoint mutable slices: the elements before `index` (excluded) and the elements from `index` (included). See [`slice::split_at_mut`].
+    ///
+    /// Panics if `index` is greater than the total size of the grid.
+    #[inline]
+    pub fn split_at_mut(&mut self, index: GridIndex) -> (&mut [D], &mut [D]) {
+        self.data.split_at_mut(index as usize)
+    }
+
+    /// Returns the number of elements for which `pred` returns `true`
+    pub fn count_where<F: Fn(&D) -> bool>(&self, pred: F) -> usize {
+        self.data.iter().filter(|d| pred(d)).count()
+    }
+
+    /// Returns the index of the first element for which `pred` returns `true`, or `None` if there is none.
+    pub fn position_of<F: Fn(&D) -> bool>(&self, pred: F) -> Option<GridIndex> {
+        self.data
+            .iter()
+            .position(pred)
+            .map(|index| index as GridIndex)
+    }
+
+    /// Returns the indexes of all the elements for which `pred` returns `true`, in increasing order.
+    pub fn indices_where<F: Fn(&D) -> bool>(&self, pred: F) -> Vec<GridIndex> {
+        self.data
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| pred(d))
+            .map(|(index, _)| index as GridIndex)
+            .collect()
+    }
+
+    /// Returns the index of the element with the maximum value of `f` and a reference to it, or `None` if the grid is empty.
+    ///
+    /// If several elements are equally maximum, the last one is returned.
+    pub fn max_by_key<B: Ord, F: Fn(&D) -> B>(&self, f: F) -> Option<(GridIndex, &D)> {
+        self.data
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, d)| f(d))
+            .map(|(index, d)| (index as GridIndex, d))
+    }
+
+    /// Returns the index of the element with the minimum value of `f` and a reference to it, or `None` if the grid is empty.
+    ///
+    /// If several elements are equally minimum, the first one is returned.
+    pub fn min_by_key<B: Ord, F: Fn(&D) -> B>(&self, f: F) -> Option<(GridIndex, &D)> {
+        self.data
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, d)| f(d))
+            .map(|(index, d)| (index as GridIndex, d))
+    }
+
     /// Returns a range of all the [GridIndex] in this grid.
     #[inline]
-    pub fn indexes(&self) -> std::ops::Range<usize> {
-        0..self.grid.total_size()
+    pub fn indexes(&self) -> core::ops::Range<GridIndex> {
+        0..self.grid.total_size() as GridIndex
+    }
+}
+
+impl<C: CoordinateSystem, D, G: Grid<C>> GridData<C, D, G> {
+    /// Computes, for each element, the minimal accumulated cost to reach it from the nearest of the `sources`, using Dijkstra's algorithm.
+    ///
+    /// - `cost` returns the cost to enter an element, or `None` if the element is impassable.
+    /// - Sources have a cost of 0. Elements that cannot be reached are set to `None`.
+    ///
+    /// Neighbours are the ones returned by [`Grid::get_neighbours_in_all_directions`], so looping is respected.
+    ///
+    /// Panics if one of the `sources` is not a valid index for this grid.
+    pub fn dijkstra_field<F: Fn(&D) -> Option<u32>>(
+        &self,
+        sources: &[GridIndex],
+        cost: F,
+    ) -> GridData<C, Option<u32>, G> {
+        let mut field = vec![None; self.grid.total_size()];
+        let mut heap = BinaryHeap::new();
+        for &source in sources {
+            assert!(
+                self.grid.is_valid_index(source),
+                "source index {} is not a valid index for a grid of {} elements",
+                source,
+                self.grid.total_size()
+            );
+            field[source as usize] = Some(0);
+            heap.push(Reverse((0u32, source)));
+        }
+
+        let mut neighbours = self.grid.neighbour_buffer();
+        while let Some(Reverse((accumulated_cost, index))) = heap.pop() {
+            if field[index as usize].is_some_and(|c| c < accumulated_cost) {
+                continue;
+            }
+            self.grid
+                .get_neighbours_in_all_directions(index, &mut neighbours);
+            for neighbour in neighbours.iter().flatten() {
+                let Some(entry_cost) = cost(self.get(*neighbour)) else {
+                    continue;
+                };
+                let neighbour_cost = accumulated_cost.saturating_add(entry_cost);
+                let field_cost = &mut field[*neighbour as usize];
+                if field_cost.is_none_or(|c| neighbour_cost < c) {
+                    *field_cost = Some(neighbour_cost);
+                    heap.push(Reverse((neighbour_cost, *neighbour)));
+                }
+            }
+        }
+        GridData::new(self.grid.clone(), field)
+    }
+
+    /// Creates a new [`GridData`] on the same grid, with each element being the result of `f` called on the corresponding elements of this grid and of `other`.
+    ///
+    /// Returns [`GridError::DimensionMismatch`] if the grids do not have the same total size, or [`GridError::GridMismatch`] if the grids are not equal.
+    pub fn zip_with<E, O, F: Fn(&D, &E) -> O>(
+        &self,
+        other: &GridData<C, E, G>,
+        f: F,
+    ) -> Result<GridData<C, O, G>, GridError>
+    where
+        G: PartialEq,
+    {
+        self.check_same_grid(other)?;
+        Ok(GridData::new(
+            self.grid.clone(),
+            self.data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(d, e)| f(d, e))
+                .collect(),
+        ))
+    }
+
+    /// Calls `f` on each element for which the corresponding element of `mask` is `true`.
+    ///
+    /// Returns [`GridError::DimensionMismatch`] if the grids do not have the same total size, or [`GridError::GridMismatch`] if the grids are not equal.
+    pub fn where_mask<F: FnMut(&mut D)>(
+        &mut self,
+        mask: &GridData<C, bool, G>,
+        mut f: F,
+    ) -> Result<(), GridError>
+    where
+        G: PartialEq,
+    {
+        self.check_same_grid(mask)?;
+        for (d, _) in self
+            .data
+            .iter_mut()
+            .zip(mask.data.iter())
+            .filter(|(_, masked)| **masked)
+        {
+            f(d);
+        }
+        Ok(())
+    }
+
+    /// Returns [`GridError::DimensionMismatch`] if `other` does not have the same total size as this grid, and [`GridError::GridMismatch`] if the grids have the same total size but are not equal.
+    ///
+    /// Comparing total sizes alone would accept grids with a different shape, such as a 2x3 and a 3x2 grid, whose elements do not line up.
+    fn check_same_grid<E>(&self, other: &GridData<C, E, G>) -> Result<(), GridError>
+    where
+        G: PartialEq,
+    {
+        if self.grid.total_size() != other.grid.total_size() {
+            return Err(GridError::DimensionMismatch {
+                expected: self.grid.total_size(),
+                got: other.grid.total_size(),
+            });
+        }
+        match self.grid == other.grid {
+            true => Ok(()),
+            false => Err(GridError::GridMismatch),
+        }
+    }
+
+    /// Labels the connected components of the grid: groups of elements linked by neighbours for which `connected` returns `true`.
+    ///
+    /// Returns a [`GridData`] containing the component id of each element, from 0 to the number of components (excluded), and the number of components.
+    ///
+    /// `connected` is called with the value of an element and the value of one of its neighbours. Neighbours are the ones returned by [`Grid::get_neighbours_in_all_directions`], so connectivity follows the directions of the coordinate system and looping is respected.
+    pub fn label_components<F: Fn(&D, &D) -> bool>(
+        &self,
+        connected: F,
+    ) -> (GridData<C, u32, G>, u32) {
+        let mut labels: Vec<Option<u32>> = vec![None; self.data.len()];
+        let mut components_count = 0;
+        let mut neighbours = self.grid.neighbour_buffer();
+        let mut queue = VecDeque::new();
+        for start in 0..self.data.len() {
+            if labels[start].is_some() {
+                continue;
+            }
+            labels[start] = Some(components_count);
+            queue.push_back(start as GridIndex);
+            while let Some(index) = queue.pop_front() {
+                self.grid
+                    .get_neighbours_in_all_directions(index, &mut neighbours);
+                for &neighbour in neighbours.iter().flatten() {
+                    let label = &mut labels[neighbour as usize];
+                    if label.is_none() && connected(self.get(index), self.get(neighbour)) {
+                        *label = Some(components_count);
+                        queue.push_back(neighbour);
+                    }
+                }
+            }
+            components_count += 1;
+        }
+        (
+            GridData::new(self.grid.clone(), labels.into_iter().flatten().collect()),
+            components_count,
+        )
+    }
+
+    /// Returns the number of connected components of the grid, see [`GridData::label_components`].
+    ///
+    /// Lighter than [`GridData::label_components`]: visited elements are tracked in a [`BitGridData`] instead of building a grid of labels.
+    pub fn count_components<F: Fn(&D, &D) -> bool>(&self, connected: F) -> usize {
+        let mut visited = BitGridData::new(self.grid.clone(), false);
+        let mut components_count = 0;
+        let mut neighbours = self.grid.neighbour_buffer();
+        let mut queue = VecDeque::new();
+        for start in 0..self.data.len() as GridIndex {
+            if visited.get(start) {
+                continue;
+            }
+            visited.set_raw(start, true);
+            queue.push_back(start);
+            while let Some(index) = queue.pop_front() {
+                self.grid
+                    .get_neighbours_in_all_directions(index, &mut neighbours);
+                for &neighbour in neighbours.iter().flatten() {
+                    if !visited.get(neighbour) && connected(self.get(index), self.get(neighbour)) {
+                        visited.set_raw(neighbour, true);
+                        queue.push_back(neighbour);
+                    }
+                }
+            }
+            components_count += 1;
+        }
+        components_count
+    }
+
+    /// Returns a new [`GridData`] where each element is the result of `rule`, called with the current value of the element and the values of its neighbours.
+    ///
+    /// Neighbours are given in the order of [`Grid::get_neighbours_in_all_directions`], with `None` for the ones outside of the grid. `self` is not modified, so every element sees the values from before the step.
+    pub fn step<F: Fn(&D, &[Option<&D>]) -> D>(&self, rule: F) -> GridData<C, D, G> {
+        let mut data = Vec::with_capacity(self.data.len());
+        self.step_with(rule, |_, value| data.push(value));
+        GridData::new(self.grid.clone(), data)
+    }
+
+    /// Same as [`GridData::step`] but writes the result into `dst`, reusing its buffer.
+    ///
+    /// Panics if `dst` does not have the same size as `self`.
+    pub fn step_into<F: Fn(&D, &[Option<&D>]) -> D>(&self, dst: &mut GridData<C, D, G>, rule: F) {
+        assert_eq!(
+            self.data.len(),
+            dst.data.len(),
+            "step destination must have the same size as the source"
+        );
+        self.step_with(rule, |index, value| dst.data[index] = value);
+    }
+
+    /// Calls `rule` on each element with its neighbour values and gives the result to `write`, along with the element index
+    fn step_with<F: Fn(&D, &[Option<&D>]) -> D, W: FnMut(usize, D)>(&self, rule: F, mut write: W) {
+        let mut neighbours = self.grid.neighbour_buffer();
+        let mut values = Vec::with_capacity(neighbours.len());
+        for (index, value) in self.data.iter().enumerate() {
+            self.grid
+                .get_neighbours_in_all_directions(index as GridIndex, &mut neighbours);
+            values.clear();
+            values.extend(neighbours.iter().map(|n| n.map(|n| self.get(n))));
+            write(index, rule(value, &values));
+        }
+    }
+}
+
+impl<C: CoordinateSystem, D: Hash + Eq + Clone, G: Grid<C>> GridData<C, D, G> {
+    /// Returns the number of occurrences of each distinct value in the grid
+    #[cfg(feature = "std")]
+    pub fn histogram(&self) -> HashMap<D, usize> {
+        let mut histogram = HashMap::new();
+        for d in self.data.iter() {
+            *histogram.entry(d.clone()).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /// Returns the values of the grid and their number of occurrences sorted by value, for a deterministic output.
+    pub fn histogram_sorted(&self) -> Vec<(D, usize)>
+    where
+        D: Ord,
+    {
+        let mut histogram = BTreeMap::new();
+        for d in self.data.iter() {
+            *histogram.entry(d.clone()).or_insert(0) += 1;
+        }
+        histogram.into_iter().collect()
+    }
+}
+
+impl<C: CoordinateSystem, G: Grid<C>> GridData<C, u32, G> {
+    /// Replaces the values of the grid, such as labels, by contiguous values in `0..n` where `n` is the number of distinct values. Elements which had the same value still share the same value.
+    ///
+    /// New values are given by first occurrence: the element at index 0 gets 0, the next element in index order with a value not seen yet gets 1, and so on. The mapping is thus stable for a given content.
+    ///
+    /// Returns `n` and the mapping from each old value to its new value.
+    #[cfg(feature = "std")]
+    pub fn compact_labels(&mut self) -> (u32, HashMap<u32, u32>) {
+        let mut mapping = HashMap::new();
+        for value in self.data.iter_mut() {
+            let next_label = mapping.len() as u32;
+            *value = *mapping.entry(*value).or_insert(next_label);
+        }
+        (mapping.len() as u32, mapping)
+    }
+}
+
+impl<C: CoordinateSystem, D: PartialEq, G: Grid<C>> GridData<C, D, G> {
+    /// Returns the number of elements equal to `value`
+    pub fn count(&self, value: &D) -> usize {
+        self.count_where(|d| d == value)
+    }
+
+    /// Returns the indexes of the elements which differ between this grid and `other`, in increasing order.
+    ///
+    /// Returns [`GridError::DimensionMismatch`] if the grids do not have the same total size, or [`GridError::GridMismatch`] if the grids are not equal.
+    pub fn diff_indices(&self, other: &GridData<C, D, G>) -> Result<Vec<GridIndex>, GridError>
+    where
+        G: PartialEq,
+    {
+        self.check_same_grid(other)?;
+        Ok(self
+            .data
+            .iter()
+            .zip(other.data.iter())
+            .enumerate()
+            .filter(|(_, (a, b))| a != b)
+            .map(|(index, _)| index as GridIndex)
+            .collect())
+    }
+}
+
+impl<C, D, G> Debug for GridData<C, D, G>
+where
+    C: CoordinateSystem,
+    D: Debug,
+    G: Grid<C> + Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GridData")
+            .field("grid", &self.grid)
+            .field("data", &self.data)
+            .finish()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<C: CoordinateSystem, D: Sync, G: Grid<C>> GridData<C, D, G> {
+    /// Returns a parallel iterator over all the elements.
+    #[inline]
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = &D> {
+        self.data.par_iter()
+    }
+
+    /// Creates a new [`GridData`] on the same grid, with each element being the result of `f` called on the corresponding element of this grid.
+    ///
+    /// `f` is called in parallel and must be `Sync`.
+    pub fn par_map<E: Send, F: Fn(&D) -> E + Sync + Send>(&self, f: F) -> GridData<C, E, G> {
+        GridData::new(self.grid.clone(), self.data.par_iter().map(f).collect())
+    }
+
+    /// Same as [`GridData::par_map`] but `f` also receives the index of the element.
+    pub fn par_map_indexed<E: Send, F: Fn(GridIndex, &D) -> E + Sync + Send>(
+        &self,
+        f: F,
+    ) -> GridData<C, E, G> {
+        GridData::new(
+            self.grid.clone(),
+            self.data
+                .par_iter()
+                .enumerate()
+                .map(|(index, d)| f(index as GridIndex, d))
+                .collect(),
+        )
+    }
+
+    /// Parallel version of [`GridData::label_components`], returning the same labels.
+    ///
+    /// The grid is split into tiles of contiguous indexes which are labelled in parallel, then the components crossing tiles boundaries are merged with a union-find. `connected` should be symmetric.
+    pub fn par_label_components<F: Fn(&D, &D) -> bool + Sync>(
+        &self,
+        connected: F,
+    ) -> (GridData<C, u32, G>, u32)
+    where
+        G: Sync,
+    {
+        let size = self.data.len();
+        let tile_len = size.div_ceil(4 * rayon::current_num_threads()).max(1);
+
+        // Label each tile with the index of the first element of each of its components, and collect the links to other tiles
+        let mut roots = vec![0 as GridIndex; size];
+        let links: Vec<Vec<(GridIndex, GridIndex)>> = roots
+            .par_chunks_mut(tile_len)
+            .enumerate()
+            .map(|(tile, tile_roots)| {
+                let start = tile * tile_len;
+                let tile_range = start..start + tile_roots.len();
+                let mut visited = vec![false; tile_roots.len()];
+                let mut links = Vec::new();
+                let mut neighbours = self.grid.neighbour_buffer();
+                let mut queue = VecDeque::new();
+                for seed in tile_range.clone() {
+                    if visited[seed - start] {
+                        continue;
+                    }
+                    visited[seed - start] = true;
+                    tile_roots[seed - start] = seed as GridIndex;
+                    queue.push_back(seed as GridIndex);
+                    while let Some(index) = queue.pop_front() {
+                        self.grid
+                            .get_neighbours_in_all_directions(index, &mut neighbours);
+                        for &neighbour in neighbours.iter().flatten() {
+                            let local = (neighbour as usize).wrapping_sub(start);
+                            if tile_range.contains(&(neighbour as usize)) && visited[local] {
+                                continue;
+                            }
+                            if !connected(self.get(index), self.get(neighbour)) {
+                                continue;
+                            }
+                            if tile_range.contains(&(neighbour as usize)) {
+                                visited[local] = true;
+                                tile_roots[local] = seed as GridIndex;
+                                queue.push_back(neighbour);
+                            } else {
+                                links.push((seed as GridIndex, neighbour));
+                            }
+                        }
+                    }
+                }
+                links
+            })
+            .collect();
+
+        // Merge the tiles components, each component being represented by its smallest index
+        let mut parents: Vec<GridIndex> =
+            (0..size).into_par_iter().map(|i| i as GridIndex).collect();
+        for (root, neighbour) in links.into_iter().flatten() {
+            let (a, b) = (
+                find_root(&mut parents, root),
+                find_root(&mut parents, roots[neighbour as usize]),
+            );
+            parents[a.max(b) as usize] = a.min(b);
+        }
+
+        // Number the components by increasing smallest index, as in the serial version
+        let mut ids = vec![0; size];
+        let mut components_count = 0;
+        for index in 0..size {
+            if roots[index] as usize != index {
+                continue;
+            }
+            let root = find_root(&mut parents, index as GridIndex);
+            parents[index] = root;
+            if root as usize == index {
+                ids[index] = components_count;
+                components_count += 1;
+            }
+        }
+        let labels = roots
+            .par_iter()
+            .map(|&root| ids[parents[root as usize] as usize])
+            .collect();
+        (GridData::new(self.grid.clone(), labels), components_count)
+    }
+}
+
+/// Returns the root of `index` in the union-find `parents`, compressing the path along the way
+#[cfg(feature = "rayon")]
+fn find_root(parents: &mut [GridIndex], mut index: GridIndex) -> GridIndex {
+    while parents[index as usize] != index {
+        let grand_parent = parents[parents[index as usize] as usize];
+        parents[index as usize] = grand_parent;
+        index = grand_parent;
+    }
+    index
+}
+
+impl<C: CoordinateSystem, D: Clone + PartialEq, G: Grid<C>> GridData<C, D, G> {
+    /// Sets the value of each element equal to `from` to `to`, and returns the number of replaced elements
+    pub fn replace(&mut self, from: &D, to: D) -> usize {
+        self.replace_where(|d| d == from, to)
+    }
+
+    /// Returns the run-length encoding of the data buffer: a list of values and the number of consecutive elements, in index order, having this value.
+    pub fn to_rle(&self) -> Vec<(D, u32)> {
+        let mut runs: Vec<(D, u32)> = Vec::new();
+        for value in self.data.iter() {
+            match runs.last_mut() {
+                Some((run_value, run_length)) if run_value == value && *run_length < u32::MAX => {
+                    *run_length += 1
+                }
+                _ => runs.push((value.clone(), 1)),
+            }
+        }
+        runs
+    }
+
+    /// Creates a [`GridData`] from a run-length encoded buffer, as returned by [`GridData::to_rle`].
+    ///
+    /// Returns an error if a run has a length of 0 or if the run lengths do not sum to the `total_size` of `grid`.
+    pub fn from_rle(grid: G, runs: &[(D, u32)]) -> Result<Self, GridError> {
+        let mut length = 0;
+        for (run_index, (_, run_length)) in runs.iter().enumerate() {
+            if *run_length == 0 {
+                return Err(GridError::EmptyRun { run_index });
+            }
+            length += *run_length as usize;
+        }
+        if length != grid.total_size() {
+            return Err(GridError::LengthMismatch {
+                expected: grid.total_size(),
+                got: length,
+            });
+        }
+        let mut data = Vec::with_capacity(length);
+        for (value, run_length) in runs {
+            data.extend(core::iter::repeat_n(value.clone(), *run_length as usize));
+        }
+        Ok(GridData::new(grid, data))
+    }
+}
+
+/// Same as [`GridData::get`]: panics if `index` is not a valid index for this grid.
+impl<C: CoordinateSystem, D, G: Grid<C>> Index<GridIndex> for GridData<C, D, G> {
+    type Output = D;
+
+    #[inline]
+    fn index(&self, index: GridIndex) -> &D {
+        self.get(index)
+    }
+}
+
+/// Same as [`GridData::get_mut`]: panics if `index` is not a valid index for this grid.
+impl<C: CoordinateSystem, D, G: Grid<C>> IndexMut<GridIndex> for GridData<C, D, G> {
+    #[inline]
+    fn index_mut(&mut self, index: GridIndex) -> &mut D {
+        self.get_mut(index)
+    }
+}
+
+/// Creates a [`GridData`] on the default grid, with each element value set to its default one
+impl<C, D, G> Default for GridData<C, D, G>
+where
+    C: CoordinateSystem,
+    D: Default,
+    G: Grid<C> + Default,
+{
+    fn default() -> Self {
+        let grid = G::default();
+        let data = core::iter::repeat_with(D::default)
+            .take(grid.total_size())
+            .collect();
+        Self::new(grid, data)
+    }
+}
+
+/// [`Clone::clone_from`] reuses the allocation of the data buffer of `self` when possible.
+impl<C, D, G> Clone for GridData<C, D, G>
+where
+    C: CoordinateSystem,
+    D: Clone,
+    G: Grid<C>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            grid: self.grid.clone(),
+            data: self.data.clone(),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.grid.clone_from(&source.grid);
+        self.data.clone_from(&source.data);
+    }
+}
+
+/// Two [`GridData`] are equal if their grids are equal and their data are equal
+impl<C, D, G> PartialEq for GridData<C, D, G>
+where
+    C: CoordinateSystem,
+    D: PartialEq,
+    G: Grid<C> + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.grid == other.grid && self.data == other.data
+    }
+}
+
+impl<C, D, G> Eq for GridData<C, D, G>
+where
+    C: CoordinateSystem,
+    D: Eq,
+    G: Grid<C> + Eq,
+{
+}
+
+/// Hashes the grid then the data, consistently with the [`PartialEq`] implementation
+impl<C, D, G> Hash for GridData<C, D, G>
+where
+    C: CoordinateSystem,
+    D: Hash,
+    G: Grid<C> + Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.grid.hash(state);
+        self.data.hash(state);
+    }
+}
+
+/// Deserialization counterpart of [`GridData`], used to validate the data buffer length before building a [`GridData`].
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "GridData")]
+struct RawGridData<D, G> {
+    grid: G,
+    data: Vec<D>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C, D, G> Deserialize<'de> for GridData<C, D, G>
+where
+    C: CoordinateSystem,
+    D: Deserialize<'de>,
+    G: Grid<C> + Deserialize<'de>,
+{
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let raw = RawGridData::<D, G>::deserialize(deserializer)?;
+        GridData::try_from_vec(raw.grid, raw.data).map_err(serde::de::Error::custom)
     }
 }
 
 impl<C: CoordinateSystem, D: Clone, G: Grid<C>> GridData<C, D, G> {
+    /// Sets each element for which the corresponding element of `mask` is `false` to `default`.
+    ///
+    /// Returns [`GridError::DimensionMismatch`] if the grids do not have the same total size, or [`GridError::GridMismatch`] if the grids are not equal.
+    pub fn apply_mask(&mut self, mask: &GridData<C, bool, G>, default: D) -> Result<(), GridError>
+    where
+        G: PartialEq,
+    {
+        self.check_same_grid(mask)?;
+        for (d, _) in self
+            .data
+            .iter_mut()
+            .zip(mask.data.iter())
+            .filter(|(_, masked)| !**masked)
+        {
+            *d = default.clone();
+        }
+        Ok(())
+    }
+
     /// Resets the whole grid buffer by setting the value of each element to `value`
     pub fn reset(&mut self, value: D) {
         for d in self.data.iter_mut() {
             *d = value.clone();
         }
     }
+
+    /// Sets the value of each element matching `predicate` to `to`, and returns the number of replaced elements
+    pub fn replace_where<F: Fn(&D) -> bool>(&mut self, predicate: F, to: D) -> usize {
+        let mut replaced = 0;
+        for d in self.data.iter_mut().filter(|d| predicate(d)) {
+            *d = to.clone();
+            replaced += 1;
+        }
+        replaced
+    }
 }
 
 /// Represents a reference to an element of a [`Grid`] or [`GridData`]
 pub trait NodeRef<C: CoordinateSystem, G: Grid<C>> {
     /// Returns the [`GridIndex`] that is referenced by this `NodeRef`.
     fn to_index(&self, grid: &G) -> GridIndex;
+
+    /// Same as [`NodeRef::to_index`] but returns an error if this `NodeRef` does not reference a valid element of `grid`.
+    ///
+    /// By default, only checks that the index is lower than the grid total size. Position-based references should override it to check the position itself.
+    fn try_to_index(&self, grid: &G) -> Result<GridIndex, GridError> {
+        let index = self.to_index(grid);
+        match (index as usize) < grid.total_size() {
+            true => Ok(index),
+            false => Err(GridError::IndexOutOfBounds {
+                index: index as usize,
+                size: grid.total_size(),
+            }),
+        }
+    }
 }
 
 impl<C: CoordinateSystem, G: Grid<C>> NodeRef<C, G> for GridIndex {
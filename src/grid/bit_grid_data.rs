@@ -0,0 +1,135 @@
+use alloc::{vec, vec::Vec};
+use core::marker::PhantomData;
+
+use crate::coordinate_system::CoordinateSystem;
+
+use super::{Grid, GridData, GridIndex, NodeRef};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Holds a [`Grid`] and one boolean per grid element, packed as bits in a buffer of `u64` words.
+///
+/// Uses 8 times less memory than a [`GridData`] of `bool`. Bits past the `total_size` of the grid in the last word are always unset.
+///
+/// As for [`GridData`], elements of a [`crate::cartesian::grid::CartesianGrid`] can also be accessed by position with `get_from_pos` and `set_from_pos`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitGridData<C, G>
+where
+    C: CoordinateSystem,
+    G: Grid<C>,
+{
+    grid: G,
+    words: Vec<u64>,
+    _phantom: PhantomData<C>,
+}
+
+impl<C: CoordinateSystem, G: Grid<C>> BitGridData<C, G> {
+    /// Creates a new [`BitGridData`] on `grid`, with all elements set to `value`
+    pub fn new(grid: G, value: bool) -> Self {
+        let words_count = grid.total_size().div_ceil(WORD_BITS);
+        let mut bit_grid = Self {
+            grid,
+            words: vec![0; words_count],
+            _phantom: PhantomData,
+        };
+        bit_grid.reset(value);
+        bit_grid
+    }
+
+    /// Creates a new [`BitGridData`] from the content of a [`GridData`] of `bool`
+    pub fn from_grid_data(grid_data: &GridData<C, bool, G>) -> Self {
+        let mut bit_grid = Self::new(grid_data.grid().clone(), false);
+        for (index, _) in grid_data.iter().enumerate().filter(|(_, value)| **value) {
+            bit_grid.set_raw(index as GridIndex, true);
+        }
+        bit_grid
+    }
+
+    /// Returns a [`GridData`] of `bool` with the same content
+    pub fn to_grid_data(&self) -> GridData<C, bool, G> {
+        GridData::new(self.grid.clone(), self.iter().collect())
+    }
+
+    /// Returns a reference to the grid definition.
+    #[inline]
+    pub fn grid(&self) -> &G {
+        &self.grid
+    }
+
+    /// Returns the buffer of words holding the bits, element `i` being bit `i % 64` of word `i / 64`.
+    #[inline]
+    pub fn as_words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Returns the value of the element at this index.
+    ///
+    /// NO CHECK is done to verify that the given index is a valid index for this grid.
+    #[inline]
+    pub fn get(&self, index: GridIndex) -> bool {
+        let index = index as usize;
+        self.words[index / WORD_BITS] & (1 << (index % WORD_BITS)) != 0
+    }
+
+    /// Sets the value of the element at this index.
+    ///
+    /// NO CHECK is done to verify that the given index is a valid index for this grid.
+    #[inline]
+    pub fn set_raw(&mut self, index: GridIndex, value: bool) {
+        let index = index as usize;
+        let word = &mut self.words[index / WORD_BITS];
+        let mask = 1 << (index % WORD_BITS);
+        if value {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    /// Sets the value of the element at `index_ref` in the grid.
+    ///
+    /// NO CHECK is done to verify that the given index is a valid index for this grid.
+    #[inline]
+    pub fn set<N: NodeRef<C, G>>(&mut self, index_ref: N, value: bool) {
+        self.set_raw(index_ref.to_index(&self.grid), value);
+    }
+
+    /// Sets all the elements to `value`
+    pub fn reset(&mut self, value: bool) {
+        self.words.fill(if value { u64::MAX } else { 0 });
+        let used_bits = self.grid.total_size() % WORD_BITS;
+        if let (true, Some(last)) = (used_bits != 0, self.words.last_mut()) {
+            *last &= (1 << used_bits) - 1;
+        }
+    }
+
+    /// Returns the number of elements set to `true`
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns an iterator over the values of all the elements, in index order
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.grid.total_size()).map(|index| self.get(index as GridIndex))
+    }
+
+    /// Returns an iterator over the indexes of the elements set to `true`, in increasing order.
+    ///
+    /// Skips whole words of unset bits, making it efficient on sparse grids.
+    pub fn iter_set_indices(&self) -> impl Iterator<Item = GridIndex> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(word_index, &word)| {
+                let mut remaining = word;
+                core::iter::from_fn(move || {
+                    if remaining == 0 {
+                        return None;
+                    }
+                    let bit = remaining.trailing_zeros() as usize;
+                    remaining &= remaining - 1;
+                    Some((word_index * WORD_BITS + bit) as GridIndex)
+                })
+            })
+    }
+}
@@ -0,0 +1,152 @@
+use alloc::vec::Vec;
+use core::{
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+    slice::{Iter, IterMut},
+};
+
+use crate::{coordinate_system::CoordinateSystem, error::GridError};
+
+use super::{Grid, GridData, GridIndex, NodeRef};
+
+/// Same as a [`GridData`] but borrows its [`Grid`] instead of owning it, so that multiple data layers can share a single grid definition without cloning it.
+///
+/// The length of the data buffer is always equal to the `total_size` of the grid.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GridDataRef<'g, C, D, G>
+where
+    C: CoordinateSystem,
+    G: Grid<C>,
+{
+    grid: &'g G,
+    data: Vec<D>,
+    _phantom: PhantomData<C>,
+}
+
+impl<'g, C, D, G> GridDataRef<'g, C, D, G>
+where
+    C: CoordinateSystem,
+    G: Grid<C>,
+{
+    /// Prefer using `default_grid_data_ref` or `new_grid_data_ref` directly on an existing grid definition to create a `GridDataRef` with a correct data Vec.
+    ///
+    /// `data.len()` must be equal to `grid.total_size()`, NO CHECK is done to verify it: accesses may panic later if it is not. See [`GridDataRef::try_from_vec`] for a checked version.
+    #[inline]
+    pub fn new(grid: &'g G, data: Vec<D>) -> Self {
+        Self {
+            grid,
+            data,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Creates a `GridDataRef` from a grid definition and a data buffer, or returns [`GridError::LengthMismatch`] if `data.len()` is not equal to `grid.total_size()`.
+    pub fn try_from_vec(grid: &'g G, data: Vec<D>) -> Result<Self, GridError> {
+        if data.len() != grid.total_size() {
+            return Err(GridError::LengthMismatch {
+                expected: grid.total_size(),
+                got: data.len(),
+            });
+        }
+        Ok(Self::new(grid, data))
+    }
+
+    /// Decomposes this `GridDataRef` into its grid definition reference and its data buffer.
+    #[inline]
+    pub fn into_raw(self) -> (&'g G, Vec<D>) {
+        (self.grid, self.data)
+    }
+
+    /// Converts this `GridDataRef` into a [`GridData`] owning a clone of the grid definition. The data buffer is moved, not copied.
+    pub fn into_grid_data(self) -> GridData<C, D, G> {
+        GridData::new(self.grid.clone(), self.data)
+    }
+
+    /// Returns a reference to the `GridDefinition` this is based on
+    #[inline]
+    pub fn grid(&self) -> &'g G {
+        self.grid
+    }
+
+    /// Sets the value of the element at `index` in the grid.
+    ///
+    /// NO CHECK is done to verify that the given index is a valid index for this grid.
+    #[inline]
+    pub fn set_raw(&mut self, index: GridIndex, value: D) {
+        self.data[index as usize] = value;
+    }
+
+    /// Sets the value of the element at `index_ref` in the grid.
+    ///
+    /// NO CHECK is done to verify that the given index is a valid index for this grid.
+    #[inline]
+    pub fn set<N: NodeRef<C, G>>(&mut self, index_ref: N, value: D) {
+        self.data[index_ref.to_index(self.grid) as usize] = value;
+    }
+
+    /// Same as [`GridDataRef::set`] but returns an error instead of panicking or writing to the wrong element if `index_ref` does not reference a valid element of the grid.
+    #[inline]
+    pub fn try_set<N: NodeRef<C, G>>(&mut self, index_ref: N, value: D) -> Result<(), GridError> {
+        let index = index_ref.try_to_index(self.grid)?;
+        self.data[index as usize] = value;
+        Ok(())
+    }
+
+    /// Returns a reference to the element at this index.
+    ///
+    /// NO CHECK is done to verify that the given index is a valid index for this grid.
+    #[inline]
+    pub fn get(&self, index: GridIndex) -> &D {
+        &self.data[index as usize]
+    }
+
+    /// Returns a mutable reference to the element at this index.
+    ///
+    /// NO CHECK is done to verify that the given index is a valid index for this grid.
+    #[inline]
+    pub fn get_mut(&mut self, index: GridIndex) -> &mut D {
+        &mut self.data[index as usize]
+    }
+
+    /// Returns the data buffer as a slice. Its length is equal to the `total_size` of the grid.
+    #[inline]
+    pub fn as_slice(&self) -> &[D] {
+        &self.data
+    }
+
+    /// Returns the data buffer as a mutable slice. Its length is equal to the `total_size` of the grid.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [D] {
+        &mut self.data
+    }
+
+    /// Returns an iterator over all the elements.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, D> {
+        self.data.iter()
+    }
+
+    /// Returns an iterator over all the elements that allows modifying each value.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, D> {
+        self.data.iter_mut()
+    }
+}
+
+/// Same as [`GridDataRef::get`]: panics if `index` is not a valid index for this grid.
+impl<C: CoordinateSystem, D, G: Grid<C>> Index<GridIndex> for GridDataRef<'_, C, D, G> {
+    type Output = D;
+
+    #[inline]
+    fn index(&self, index: GridIndex) -> &D {
+        self.get(index)
+    }
+}
+
+/// Same as [`GridDataRef::get_mut`]: panics if `index` is not a valid index for this grid.
+impl<C: CoordinateSystem, D, G: Grid<C>> IndexMut<GridIndex> for GridDataRef<'_, C, D, G> {
+    #[inline]
+    fn index_mut(&mut self, index: GridIndex) -> &mut D {
+        self.get_mut(index)
+    }
+}
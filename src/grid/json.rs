@@ -0,0 +1,35 @@
+//! The serde representation of a [`GridData`] is self-contained: it holds the grid definition along with the data buffer, and deserialization checks that the buffer length matches the grid. These helpers only pick JSON as the format.
+
+use std::io::{Read, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::coordinate_system::CoordinateSystem;
+
+use super::{Grid, GridData};
+
+impl<C, D, G> GridData<C, D, G>
+where
+    C: CoordinateSystem,
+    D: Serialize,
+    G: Grid<C> + Serialize,
+{
+    /// Writes the grid definition and the data of this [`GridData`] as JSON to `writer`.
+    pub fn save_to_writer<W: Write>(&self, writer: W) -> Result<(), serde_json::Error> {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+impl<C, D, G> GridData<C, D, G>
+where
+    C: CoordinateSystem,
+    D: for<'de> Deserialize<'de>,
+    G: Grid<C> + for<'de> Deserialize<'de>,
+{
+    /// Reads a [`GridData`] written by [`GridData::save_to_writer`] from `reader`.
+    ///
+    /// Returns an error if the JSON is invalid or if the length of the data does not match the total size of the grid.
+    pub fn load_from_reader<R: Read>(reader: R) -> Result<Self, serde_json::Error> {
+        serde_json::from_reader(reader)
+    }
+}
@@ -0,0 +1,111 @@
+use alloc::{vec, vec::Vec};
+use core::marker::PhantomData;
+
+use crate::coordinate_system::CoordinateSystem;
+
+use super::{Grid, GridData, GridIndex};
+
+/// Wraps a [`Grid`] and caches the neighbours of all its elements, to serve [`Grid::get_neighbours_in_all_directions`] and [`Grid::neighbour`] without recomputing them.
+///
+/// Uses `total_size * directions_count` additional memory. Sizes and positions are delegated to the wrapped grid, which cannot be modified.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PrecomputedNeighbours<C: CoordinateSystem, G: Grid<C>> {
+    grid: G,
+    /// Neighbours of element `i` in direction `d` are at `i * directions_count + d`
+    neighbours: Vec<Option<GridIndex>>,
+    _phantom: PhantomData<C>,
+}
+
+impl<C: CoordinateSystem, G: Grid<C>> PrecomputedNeighbours<C, G> {
+    /// Creates a new [`PrecomputedNeighbours`], computing the neighbours of all the elements of `grid`
+    pub fn new(grid: G) -> Self {
+        let directions_count = grid.directions_count();
+        let mut neighbours = Vec::with_capacity(grid.total_size() * directions_count);
+        let mut buffer = grid.neighbour_buffer();
+        for index in 0..grid.total_size() {
+            grid.reset_neighbour_buffer(&mut buffer);
+            grid.get_neighbours_in_all_directions(index as GridIndex, &mut buffer);
+            neighbours.extend_from_slice(&buffer[..directions_count]);
+        }
+        Self {
+            grid,
+            neighbours,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the wrapped grid
+    #[inline]
+    pub fn inner(&self) -> &G {
+        &self.grid
+    }
+
+    /// Returns the cached neighbours of the element at `index`, indexed by direction index.
+    ///
+    /// NO CHECK is done to verify that the given index is a valid index for this grid.
+    #[inline]
+    pub fn neighbours(&self, index: GridIndex) -> &[Option<GridIndex>] {
+        let directions_count = self.grid.directions_count();
+        let start = index as usize * directions_count;
+        &self.neighbours[start..start + directions_count]
+    }
+
+    /// Creates a default [`GridData`] with the size of the grid with each element value set to its default one.
+    pub fn default_grid_data<D: Default + Clone>(&self) -> GridData<C, D, Self> {
+        GridData::new(self.clone(), vec![D::default(); self.total_size()])
+    }
+
+    /// Creates a [`GridData`] with the size of the grid with each element value being a copy of the given one.
+    pub fn new_grid_data<D: Clone>(&self, element: D) -> GridData<C, D, Self> {
+        GridData::new(self.clone(), vec![element; self.total_size()])
+    }
+}
+
+impl<C: CoordinateSystem, G: Grid<C>> Grid<C> for PrecomputedNeighbours<C, G> {
+    type Position = G::Position;
+
+    #[inline]
+    fn coord_system(&self) -> &C {
+        self.grid.coord_system()
+    }
+
+    #[inline]
+    fn directions_count(&self) -> usize {
+        self.grid.directions_count()
+    }
+
+    #[inline]
+    fn total_size(&self) -> usize {
+        self.grid.total_size()
+    }
+
+    #[inline]
+    fn get_neighbours_in_all_directions(
+        &self,
+        grid_index: GridIndex,
+        neighbours_buffer: &mut Vec<Option<GridIndex>>,
+    ) {
+        let neighbours = self.neighbours(grid_index);
+        neighbours_buffer[..neighbours.len()].copy_from_slice(neighbours);
+    }
+
+    #[inline]
+    fn neighbour(&self, index: GridIndex, direction: C::Direction) -> Option<GridIndex> {
+        self.neighbours(index)[direction.into()]
+    }
+
+    #[inline]
+    fn index_from_pos(&self, pos: &Self::Position) -> GridIndex {
+        self.grid.index_from_pos(pos)
+    }
+
+    #[inline]
+    fn pos_from_index(&self, index: GridIndex) -> Self::Position {
+        self.grid.pos_from_index(index)
+    }
+
+    #[inline]
+    fn is_valid_pos(&self, pos: &Self::Position) -> bool {
+        self.grid.is_valid_pos(pos)
+    }
+}
@@ -0,0 +1,52 @@
+//! Use with `#[serde(with = "ghx_grid::grid::serde_rle")]` on a [`GridData`] field to serialize its data buffer as a list of runs instead of a list of elements.
+
+use alloc::vec::Vec;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::coordinate_system::CoordinateSystem;
+
+use super::{Grid, GridData};
+
+#[derive(Serialize)]
+#[serde(rename = "GridData")]
+struct RleGridDataRef<'a, D, G> {
+    grid: &'a G,
+    runs: Vec<(D, u32)>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GridData")]
+struct RleGridData<D, G> {
+    grid: G,
+    runs: Vec<(D, u32)>,
+}
+
+/// Serializes `grid_data` as its grid and the run-length encoding of its data
+pub fn serialize<C, D, G, S>(
+    grid_data: &GridData<C, D, G>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    C: CoordinateSystem,
+    D: Clone + PartialEq + Serialize,
+    G: Grid<C> + Serialize,
+    S: Serializer,
+{
+    RleGridDataRef {
+        grid: grid_data.grid(),
+        runs: grid_data.to_rle(),
+    }
+    .serialize(serializer)
+}
+
+/// Deserializes a [`GridData`] serialized with [`serialize`], validating the runs against the grid size
+pub fn deserialize<'de, C, D, G, De>(deserializer: De) -> Result<GridData<C, D, G>, De::Error>
+where
+    C: CoordinateSystem,
+    D: Clone + PartialEq + Deserialize<'de>,
+    G: Grid<C> + Deserialize<'de>,
+    De: Deserializer<'de>,
+{
+    let raw = RleGridData::<D, G>::deserialize(deserializer)?;
+    GridData::from_rle(raw.grid, &raw.runs).map_err(serde::de::Error::custom)
+}
@@ -1,6 +1,13 @@
 #![warn(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
+// `GridIndex` is already a `usize` with `large-grids`, making some casts redundant
+#![cfg_attr(feature = "large-grids", allow(clippy::unnecessary_cast))]
 
 //! This library provides utilities to manipulate 2d & 3d grid data
+//!
+//! The core of the crate is `no_std` compatible (with `alloc`) when the default `std` feature is disabled.
+
+extern crate alloc;
 
 /// Defines directions
 pub mod direction;
@@ -11,5 +18,19 @@ pub mod coordinate_system;
 /// Defines grid structures
 pub mod grid;
 
+/// Defines the errors of this crate
+pub mod error;
+
 /// Defines cartesian coordinates and grids
 pub mod cartesian;
+
+/// Defines staggered coordinates and grids
+pub mod staggered;
+
+/// Defines Bevy helpers to spawn grids
+#[cfg(feature = "bevy")]
+pub mod ecs;
+
+/// Defines helpers to register the reflected types of this crate
+#[cfg(feature = "reflect")]
+pub mod reflect;
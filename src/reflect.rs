@@ -0,0 +1,49 @@
+use bevy::{
+    app::{App, Plugin},
+    ecs::reflect::AppTypeRegistry,
+    reflect::TypeRegistry,
+};
+
+use crate::{
+    cartesian::{
+        coordinates::{
+            Cartesian2D, Cartesian3D, CartesianPosition, CartesianPosition2D, GridDelta,
+        },
+        custom::{CustomCoordinates, CustomDirection, CustomGrid},
+        grid::CartesianGrid,
+    },
+    direction::{Axis, Direction},
+    staggered::{
+        coordinates::{Staggered2D, StaggeredDirection},
+        grid::StaggeredGrid,
+    },
+};
+
+/// Registers the reflected types of this crate in `registry`
+pub fn register_types(registry: &mut TypeRegistry) {
+    registry.register::<Axis>();
+    registry.register::<Direction>();
+    registry.register::<Cartesian2D>();
+    registry.register::<Cartesian3D>();
+    registry.register::<CartesianPosition>();
+    registry.register::<CartesianPosition2D>();
+    registry.register::<GridDelta>();
+    registry.register::<CartesianGrid<Cartesian2D>>();
+    registry.register::<CartesianGrid<Cartesian3D>>();
+    registry.register::<CustomCoordinates>();
+    registry.register::<CustomDirection>();
+    registry.register::<CustomGrid>();
+    registry.register::<Staggered2D>();
+    registry.register::<StaggeredDirection>();
+    registry.register::<StaggeredGrid>();
+}
+
+/// Bevy plugin registering the reflected types of this crate, see [`register_types`]
+#[derive(Default, Debug, Clone, Copy)]
+pub struct GhxGridPlugin;
+
+impl Plugin for GhxGridPlugin {
+    fn build(&self, app: &mut App) {
+        register_types(&mut app.world().resource::<AppTypeRegistry>().write());
+    }
+}
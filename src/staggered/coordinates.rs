@@ -0,0 +1,110 @@
+use crate::{
+    coordinate_system::CoordinateSystem,
+    direction::{Axis, DirectionIndex, DirectionTrait},
+};
+
+#[cfg(feature = "bevy")]
+use bevy::ecs::component::Component;
+#[cfg(feature = "reflect")]
+use bevy::{ecs::reflect::ReflectComponent, reflect::Reflect};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// 2d staggered coordinate system: rows are offset by half a cell every other row, giving each cell 6 neighbours.
+///
+/// Odd rows are shifted by half a cell towards X+ compared to even rows.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Staggered2D;
+impl CoordinateSystem for Staggered2D {
+    type Direction = StaggeredDirection;
+
+    #[inline]
+    fn directions(&self) -> &'static [StaggeredDirection] {
+        STAGGERED_2D_DIRECTIONS
+    }
+
+    #[inline]
+    fn directions_count(&self) -> usize {
+        STAGGERED_2D_DIRECTIONS.len()
+    }
+}
+
+/// Represents a direction in a [`Staggered2D`] coordinate system. North is towards Y+ and East towards X+.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StaggeredDirection {
+    /// X+, same row
+    #[default]
+    East = 0,
+    /// Row above (Y+), towards X+
+    NorthEast = 1,
+    /// Row above (Y+), towards X-
+    NorthWest = 2,
+    /// X-, same row
+    West = 3,
+    /// Row below (Y-), towards X-
+    SouthWest = 4,
+    /// Row below (Y-), towards X+
+    SouthEast = 5,
+}
+impl DirectionTrait for StaggeredDirection {
+    /// Returns the opposite [`StaggeredDirection`]
+    fn opposite(&self) -> StaggeredDirection {
+        STAGGERED_2D_DIRECTIONS[(*self as usize + 3) % STAGGERED_2D_DIRECTIONS.len()]
+    }
+
+    /// All the directions in counter-clockwise order, starting from this one.
+    fn rotation_basis(&self) -> &'static [StaggeredDirection] {
+        match self {
+            StaggeredDirection::East => &STAGGERED_ROTATIONS[0..6],
+            StaggeredDirection::NorthEast => &STAGGERED_ROTATIONS[1..7],
+            StaggeredDirection::NorthWest => &STAGGERED_ROTATIONS[2..8],
+            StaggeredDirection::West => &STAGGERED_ROTATIONS[3..9],
+            StaggeredDirection::SouthWest => &STAGGERED_ROTATIONS[4..10],
+            StaggeredDirection::SouthEast => &STAGGERED_ROTATIONS[5..11],
+        }
+    }
+
+    /// [`Axis::X`] for directions staying on the same row, [`Axis::Y`] for directions changing row.
+    fn axis(&self) -> Axis {
+        match self {
+            StaggeredDirection::East | StaggeredDirection::West => Axis::X,
+            _ => Axis::Y,
+        }
+    }
+}
+impl From<StaggeredDirection> for DirectionIndex {
+    fn from(item: StaggeredDirection) -> Self {
+        item as Self
+    }
+}
+
+/// All the directions that forms a 2d staggered coordinate system, in counter-clockwise order
+pub const STAGGERED_2D_DIRECTIONS: &[StaggeredDirection] = &[
+    StaggeredDirection::East,
+    StaggeredDirection::NorthEast,
+    StaggeredDirection::NorthWest,
+    StaggeredDirection::West,
+    StaggeredDirection::SouthWest,
+    StaggeredDirection::SouthEast,
+];
+
+const STAGGERED_ROTATIONS: &[StaggeredDirection] = &[
+    StaggeredDirection::East,
+    StaggeredDirection::NorthEast,
+    StaggeredDirection::NorthWest,
+    StaggeredDirection::West,
+    StaggeredDirection::SouthWest,
+    StaggeredDirection::SouthEast,
+    StaggeredDirection::East,
+    StaggeredDirection::NorthEast,
+    StaggeredDirection::NorthWest,
+    StaggeredDirection::West,
+    StaggeredDirection::SouthWest,
+];
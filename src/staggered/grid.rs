@@ -0,0 +1,282 @@
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use crate::{
+    cartesian::{coordinates::CartesianPosition2D, grid::checked_size_xy},
+    coordinate_system::CoordinateSystem,
+    direction::Axis,
+    error::GridError,
+    grid::{Grid, GridData, GridIndex, NodeRef},
+};
+
+use super::coordinates::{Staggered2D, StaggeredDirection};
+
+#[cfg(feature = "bevy")]
+use bevy::ecs::component::Component;
+#[cfg(feature = "reflect")]
+use bevy::{ecs::reflect::ReflectComponent, reflect::Reflect};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Definition of a 2d grid using a [`Staggered2D`] coordinate system.
+///
+/// Elements are stored row by row, the same way as a 2d [`crate::cartesian::grid::CartesianGrid`].
+///
+/// Deserialized grids are validated as by [`StaggeredGrid::try_new`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "StaggeredGridFields"))]
+pub struct StaggeredGrid {
+    size_x: u32,
+    size_y: u32,
+    looping_x: bool,
+    looping_y: bool,
+    coord_system: Staggered2D,
+}
+
+/// Serialized fields of a [`StaggeredGrid`], validated when converted to a grid
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "StaggeredGrid")]
+struct StaggeredGridFields {
+    size_x: u32,
+    size_y: u32,
+    looping_x: bool,
+    looping_y: bool,
+    #[allow(dead_code)]
+    coord_system: Staggered2D,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<StaggeredGridFields> for StaggeredGrid {
+    type Error = GridError;
+
+    fn try_from(fields: StaggeredGridFields) -> Result<Self, Self::Error> {
+        StaggeredGrid::try_new(
+            fields.size_x,
+            fields.size_y,
+            fields.looping_x,
+            fields.looping_y,
+        )
+    }
+}
+
+/// Creates a 1x1 grid without looping
+impl Default for StaggeredGrid {
+    fn default() -> Self {
+        Self::new(1, 1, false, false)
+    }
+}
+
+impl Grid<Staggered2D> for StaggeredGrid {
+    type Position = CartesianPosition2D;
+
+    #[inline]
+    fn coord_system(&self) -> &Staggered2D {
+        &self.coord_system
+    }
+
+    #[inline]
+    fn directions_count(&self) -> usize {
+        self.coord_system.directions_count()
+    }
+
+    #[inline]
+    fn total_size(&self) -> usize {
+        self.size_x as usize * self.size_y as usize
+    }
+
+    fn get_neighbours_in_all_directions(
+        &self,
+        grid_index: GridIndex,
+        neighbours_buffer: &mut Vec<Option<GridIndex>>,
+    ) {
+        let pos = self.pos_from_index(grid_index);
+        for dir in self.coord_system.directions() {
+            neighbours_buffer[usize::from(*dir)] = self
+                .get_next_pos_in_direction(&pos, *dir)
+                .map(|next_pos| self.index_from_pos(&next_pos));
+        }
+    }
+
+    #[inline]
+    fn index_from_pos(&self, grid_position: &CartesianPosition2D) -> GridIndex {
+        StaggeredGrid::index_from_pos(self, grid_position)
+    }
+
+    #[inline]
+    fn pos_from_index(&self, grid_index: GridIndex) -> CartesianPosition2D {
+        StaggeredGrid::pos_from_index(self, grid_index)
+    }
+
+    /// Positions past the end of a row map to a valid index in the next row, so the position is checked on each axis
+    #[inline]
+    fn is_valid_pos(&self, grid_position: &CartesianPosition2D) -> bool {
+        grid_position.x < self.size_x && grid_position.y < self.size_y
+    }
+}
+
+impl fmt::Display for StaggeredGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "( size: {} {}, looping: {} {} )",
+            self.size_x, self.size_y, self.looping_x, self.looping_y
+        )
+    }
+}
+
+impl StaggeredGrid {
+    /// Creates a new [`StaggeredGrid`]
+    ///
+    /// Use `looping` to specify if the coordinates on an axis should loop when reaching the end of the axis.
+    ///
+    /// Panics if any size is 0, if the total size of the grid overflows, or if `looping_y` is `true` and `size_y` is odd. See [`StaggeredGrid::try_new`] for a fallible version.
+    pub fn new(size_x: u32, size_y: u32, looping_x: bool, looping_y: bool) -> StaggeredGrid {
+        match Self::try_new(size_x, size_y, looping_x, looping_y) {
+            Ok(grid) => grid,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Creates a new [`StaggeredGrid`], returning an error if the sizes are not valid.
+    ///
+    /// Returns [`GridError::ZeroDimension`] if any size is 0, [`GridError::DimensionOverflow`] if the total size of the grid does not fit in the grid index type, and [`GridError::OddLoopingSize`] if `looping_y` is `true` and `size_y` is odd: rows parity would not be preserved when looping.
+    pub fn try_new(
+        size_x: u32,
+        size_y: u32,
+        looping_x: bool,
+        looping_y: bool,
+    ) -> Result<StaggeredGrid, GridError> {
+        checked_size_xy(size_x, size_y, 1)?;
+        if looping_y && !size_y.is_multiple_of(2) {
+            return Err(GridError::OddLoopingSize {
+                axis: Axis::Y,
+                size: size_y,
+            });
+        }
+        Ok(Self {
+            size_x,
+            size_y,
+            looping_x,
+            looping_y,
+            coord_system: Staggered2D,
+        })
+    }
+
+    /// Returns the size of the grid in the X axis.
+    #[inline]
+    pub fn size_x(&self) -> u32 {
+        self.size_x
+    }
+
+    /// Returns the size of the grid in the Y axis.
+    #[inline]
+    pub fn size_y(&self) -> u32 {
+        self.size_y
+    }
+
+    /// Returns the index from a grid position.
+    ///
+    /// NO CHECK is done to verify that the given `grid_position` is a valid position for this grid.
+    #[inline]
+    pub fn index_from_pos(&self, grid_position: &CartesianPosition2D) -> GridIndex {
+        (u64::from(grid_position.x) + u64::from(grid_position.y) * u64::from(self.size_x))
+            as GridIndex
+    }
+
+    /// Returns a [`CartesianPosition2D`] from the index of an element in this [`StaggeredGrid`].
+    ///
+    /// NO CHECK is done to verify that the given index is a valid index for this grid.
+    #[inline]
+    pub fn pos_from_index(&self, grid_index: GridIndex) -> CartesianPosition2D {
+        let index = grid_index as u64;
+        let size_x = u64::from(self.size_x);
+        CartesianPosition2D {
+            x: (index % size_x) as u32,
+            y: (index / size_x) as u32,
+        }
+    }
+
+    /// Returns the next position in the grid when moving 1 unit in `direction` from `grid_position`.
+    ///
+    /// The X offset of a move to another row depends on the parity of the row of `grid_position`.
+    ///
+    /// Returns `None` if the destination is not in the grid.
+    ///
+    /// NO CHECK is done to verify that the given `grid_position` is a valid position for this grid.
+    pub fn get_next_pos_in_direction(
+        &self,
+        grid_position: &CartesianPosition2D,
+        direction: StaggeredDirection,
+    ) -> Option<CartesianPosition2D> {
+        let odd_row = grid_position.y % 2 == 1;
+        let (dx, dy) = match direction {
+            StaggeredDirection::East => (1, 0),
+            StaggeredDirection::West => (-1, 0),
+            StaggeredDirection::NorthEast => (if odd_row { 1 } else { 0 }, 1),
+            StaggeredDirection::NorthWest => (if odd_row { 0 } else { -1 }, 1),
+            StaggeredDirection::SouthEast => (if odd_row { 1 } else { 0 }, -1),
+            StaggeredDirection::SouthWest => (if odd_row { 0 } else { -1 }, -1),
+        };
+        Some(CartesianPosition2D {
+            x: next_coord(grid_position.x, dx, self.size_x, self.looping_x)?,
+            y: next_coord(grid_position.y, dy, self.size_y, self.looping_y)?,
+        })
+    }
+
+    /// Returns the index of the next position in the grid when moving 1 unit in `direction` from `grid_position`.
+    ///
+    /// Returns `None` if the destination is not in the grid.
+    ///
+    /// NO CHECK is done to verify that the given `grid_position` is a valid position for this grid.
+    pub fn get_next_index_in_direction(
+        &self,
+        grid_position: &CartesianPosition2D,
+        direction: StaggeredDirection,
+    ) -> Option<GridIndex> {
+        self.get_next_pos_in_direction(grid_position, direction)
+            .map(|next_pos| self.index_from_pos(&next_pos))
+    }
+
+    /// Creates a default [`GridData`] with the size of the [`StaggeredGrid`] with each element value set to its default one.
+    pub fn default_grid_data<D: Default + Clone>(&self) -> GridData<Staggered2D, D, StaggeredGrid> {
+        GridData::new(self.clone(), vec![D::default(); self.total_size()])
+    }
+
+    /// Creates a [`GridData`] with the size of the [`StaggeredGrid`] with each element value being a copy of the given one.
+    pub fn new_grid_data<D: Clone>(&self, element: D) -> GridData<Staggered2D, D, StaggeredGrid> {
+        GridData::new(self.clone(), vec![element; self.total_size()])
+    }
+}
+
+impl NodeRef<Staggered2D, StaggeredGrid> for CartesianPosition2D {
+    #[inline]
+    fn to_index(&self, grid: &StaggeredGrid) -> GridIndex {
+        grid.index_from_pos(self)
+    }
+
+    #[inline]
+    fn try_to_index(&self, grid: &StaggeredGrid) -> Result<GridIndex, GridError> {
+        if self.x >= grid.size_x || self.y >= grid.size_y {
+            return Err(GridError::PositionOutOfBounds {
+                position: (self.x, self.y, 0),
+                size: (grid.size_x, grid.size_y, 1),
+            });
+        }
+        Ok(grid.index_from_pos(self))
+    }
+}
+
+/// Moves `coord` by `delta` (-1, 0 or 1) on an axis of size `size`, looping if needed.
+#[inline]
+fn next_coord(coord: u32, delta: i32, size: u32, looping: bool) -> Option<u32> {
+    let next = i64::from(coord) + i64::from(delta);
+    match looping {
+        true => Some(next.rem_euclid(i64::from(size)) as u32),
+        false => (0..i64::from(size)).contains(&next).then_some(next as u32),
+    }
+}
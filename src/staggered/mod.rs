@@ -0,0 +1,5 @@
+/// Staggered coordinates
+pub mod coordinates;
+
+/// Staggered implementation of the generic [`crate::grid::Grid`] trait
+pub mod grid;
@@ -0,0 +1,33 @@
+use ghx_grid::{
+    cartesian::{coordinates::CartesianPosition, grid::CartesianGrid},
+    grid::bit_grid_data::BitGridData,
+};
+
+#[test]
+fn position_accessors() {
+    let grid = CartesianGrid::new_cartesian_3d(5, 4, 3, false, false, false);
+    let mut bits = BitGridData::new(grid.clone(), false);
+    let pos = CartesianPosition::new(4, 3, 2);
+    bits.set_from_pos(&pos, true);
+    assert!(bits.get_from_pos(&pos));
+    assert!(bits.get(grid.index_from_pos(&pos)));
+    assert!(!bits.get_from_pos(&CartesianPosition::new(3, 3, 2)));
+    assert_eq!(bits.count_ones(), 1);
+
+    bits.set_from_pos(&pos, false);
+    assert!(!bits.get_from_pos(&pos));
+    assert_eq!(bits.count_ones(), 0);
+}
+
+#[test]
+fn position_accessors_match_grid_data() {
+    let grid = CartesianGrid::new_cartesian_2d(9, 9, false, false);
+    let data = grid.grid_data_from_fn(|pos| (pos.x * pos.y) % 3 == 0);
+    let bits = BitGridData::from_grid_data(&data);
+    for y in 0..9 {
+        for x in 0..9 {
+            let pos = CartesianPosition::new_xy(x, y);
+            assert_eq!(bits.get_from_pos(&pos), *data.get_from_pos(&pos));
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use ghx_grid::{cartesian::coordinates::GridDelta, direction::Axis};
+
+const AXES: [Axis; 3] = [Axis::X, Axis::Y, Axis::Z];
+
+fn deltas() -> [GridDelta; 4] {
+    [
+        GridDelta::new(1, 0, 0),
+        GridDelta::new(0, -2, 0),
+        GridDelta::new(3, -5, 7),
+        GridDelta::new(-4, 0, 9),
+    ]
+}
+
+#[test]
+fn four_cw_rotations_return_the_original() {
+    for axis in AXES {
+        for delta in deltas() {
+            let mut rotated = delta;
+            for _ in 0..4 {
+                rotated = rotated.rotated_cw_about(axis);
+            }
+            assert_eq!(rotated, delta, "around {:?}", axis);
+        }
+    }
+}
+
+#[test]
+fn four_ccw_rotations_return_the_original() {
+    for axis in AXES {
+        for delta in deltas() {
+            let mut rotated = delta;
+            for _ in 0..4 {
+                rotated = rotated.rotated_ccw_about(axis);
+            }
+            assert_eq!(rotated, delta, "around {:?}", axis);
+        }
+    }
+}
+
+#[test]
+fn cw_is_the_inverse_of_ccw() {
+    for axis in AXES {
+        for delta in deltas() {
+            assert_eq!(delta.rotated_ccw_about(axis).rotated_cw_about(axis), delta);
+        }
+    }
+}
+
+#[test]
+fn ccw_rotation_around_z() {
+    assert_eq!(
+        GridDelta::new(1, 0, 0).rotated_ccw_about(Axis::Z),
+        GridDelta::new(0, 1, 0)
+    );
+    assert_eq!(
+        GridDelta::new(3, -5, 7).rotated_ccw_about(Axis::Z),
+        GridDelta::new(5, 3, 7)
+    );
+}
@@ -0,0 +1,22 @@
+use ghx_grid::cartesian::coordinates::{Cartesian2D, Cartesian3D, CartesianCoordinates};
+
+fn assert_deltas_aligned<C: CartesianCoordinates>(coord_system: &C) {
+    assert_eq!(coord_system.directions().len(), coord_system.deltas().len());
+    assert_eq!(
+        coord_system.directions_and_deltas().count(),
+        coord_system.directions_count()
+    );
+    for (direction, delta) in coord_system.directions_and_deltas() {
+        assert_eq!(delta, direction.delta(), "direction {}", direction);
+    }
+}
+
+#[test]
+fn directions_and_deltas_aligned_2d() {
+    assert_deltas_aligned(&Cartesian2D);
+}
+
+#[test]
+fn directions_and_deltas_aligned_3d() {
+    assert_deltas_aligned(&Cartesian3D);
+}
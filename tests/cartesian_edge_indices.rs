@@ -0,0 +1,91 @@
+use std::collections::HashSet;
+
+use ghx_grid::{
+    cartesian::{
+        coordinates::{Cartesian2D, Cartesian3D, CartesianCoordinates},
+        grid::CartesianGrid,
+    },
+    grid::GridIndex,
+};
+
+/// Brute force reference: elements with a coordinate at the bounds of a non-looping axis
+fn expected_edges<C: CartesianCoordinates>(
+    grid: &CartesianGrid<C>,
+    looping: [bool; 3],
+    axes: usize,
+) -> Vec<GridIndex> {
+    let (size_x, size_y, size_z) = grid.size();
+    grid.indexes()
+        .filter(|&index| {
+            let pos = grid.pos_from_index(index);
+            [(pos.x, size_x), (pos.y, size_y), (pos.z, size_z)]
+                .iter()
+                .zip(looping)
+                .take(axes)
+                .any(|(&(coord, size), looping)| !looping && (coord == 0 || coord == size - 1))
+        })
+        .collect()
+}
+
+fn assert_edges<C: CartesianCoordinates>(grid: &CartesianGrid<C>, looping: [bool; 3], axes: usize) {
+    let edges: Vec<GridIndex> = grid.edge_indices().collect();
+    let unique: HashSet<GridIndex> = edges.iter().copied().collect();
+    assert_eq!(
+        unique.len(),
+        edges.len(),
+        "duplicated edge indices in {grid}"
+    );
+    assert_eq!(edges, expected_edges(grid, looping, axes), "{grid}");
+}
+
+#[test]
+fn edge_indices_2d_perimeter() {
+    let grid = CartesianGrid::new_cartesian_2d(4, 3, false, false);
+    // Corners are only counted once: 2 * 4 + 2 * 3 - 4
+    assert_eq!(grid.edge_indices().count(), 10);
+    assert_eq!(
+        grid.edge_indices().collect::<Vec<_>>(),
+        vec![0, 1, 2, 3, 4, 7, 8, 9, 10, 11]
+    );
+    assert_edges(&grid, [false; 3], 2);
+}
+
+#[test]
+fn edge_indices_3d_surface() {
+    let grid = CartesianGrid::new_cartesian_3d(4, 5, 6, false, false, false);
+    // Total minus the interior cells
+    assert_eq!(grid.edge_indices().count(), 4 * 5 * 6 - 2 * 3 * 4);
+    assert_edges(&grid, [false; 3], 3);
+}
+
+#[test]
+fn edge_indices_thin_grids() {
+    for (x, y) in [(1, 1), (1, 4), (4, 1), (2, 2)] {
+        let grid = CartesianGrid::new_cartesian_2d(x, y, false, false);
+        assert_eq!(grid.edge_indices().count(), (x * y) as usize);
+        assert_edges(&grid, [false; 3], 2);
+    }
+    let grid = CartesianGrid::new_cartesian_3d(3, 3, 1, false, false, false);
+    assert_eq!(grid.edge_indices().count(), 9);
+}
+
+#[test]
+fn edge_indices_looping_axes_have_no_edges() {
+    let grid = CartesianGrid::<Cartesian2D>::new_cartesian_2d(4, 3, true, false);
+    assert_eq!(
+        grid.edge_indices().collect::<Vec<_>>(),
+        vec![0, 1, 2, 3, 8, 9, 10, 11]
+    );
+    assert_edges(&grid, [true, false, false], 2);
+
+    let grid = CartesianGrid::new_cartesian_2d(4, 3, true, true);
+    assert_eq!(grid.edge_indices().count(), 0);
+
+    for flags in 0..8 {
+        let looping = [flags & 1 != 0, flags & 2 != 0, flags & 4 != 0];
+        let grid = CartesianGrid::<Cartesian3D>::new_cartesian_3d(
+            3, 4, 5, looping[0], looping[1], looping[2],
+        );
+        assert_edges(&grid, looping, 3);
+    }
+}
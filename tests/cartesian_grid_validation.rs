@@ -0,0 +1,48 @@
+use ghx_grid::{
+    cartesian::{coordinates::Cartesian2D, grid::CartesianGrid},
+    error::GridError,
+    grid::GridData,
+};
+
+#[test]
+fn builder_rejects_invalid_sizes() {
+    let zero = CartesianGrid::<Cartesian2D>::builder()
+        .size(0, 3, 1)
+        .try_build();
+    assert!(matches!(zero, Err(GridError::ZeroDimension { .. })));
+
+    let overflow = CartesianGrid::<Cartesian2D>::builder()
+        .size(70000, 70000, 1)
+        .try_build();
+    assert!(matches!(overflow, Err(GridError::DimensionOverflow { .. })));
+
+    let grid = CartesianGrid::<Cartesian2D>::builder()
+        .size(2, 3, 1)
+        .try_build()
+        .unwrap();
+    assert_eq!(grid.size(), (2, 3, 1));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_validates_sizes() {
+    let grid = CartesianGrid::new_cartesian_2d(2, 3, false, true);
+    let json = serde_json::to_string(&grid).unwrap();
+    let deserialized: CartesianGrid<Cartesian2D> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, grid);
+
+    let zero_sized = json.replace("\"size_x\":2", "\"size_x\":0");
+    assert!(serde_json::from_str::<CartesianGrid<Cartesian2D>>(&zero_sized).is_err());
+
+    // The cached `size_xy` is recomputed from the sizes
+    let wrong_cache = json.replace("\"size_xy\":6", "\"size_xy\":1");
+    let deserialized: CartesianGrid<Cartesian2D> = serde_json::from_str(&wrong_cache).unwrap();
+    assert_eq!(deserialized, grid);
+}
+
+#[test]
+fn default_grid_data_matches_its_grid() {
+    let data = GridData::<Cartesian2D, u8, CartesianGrid<Cartesian2D>>::default();
+    assert_eq!(data.grid(), &CartesianGrid::default());
+    assert_eq!(data.as_slice(), &[0]);
+}
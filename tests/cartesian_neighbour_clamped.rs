@@ -0,0 +1,52 @@
+use ghx_grid::{
+    cartesian::{coordinates::CartesianPosition, grid::CartesianGrid},
+    direction::Direction,
+};
+
+#[test]
+fn clamped_to_the_edges() {
+    let grid = CartesianGrid::new_cartesian_2d(3, 3, false, false);
+    let corner = CartesianPosition::new_xy(0, 0);
+    assert_eq!(
+        grid.get_neighbour_clamped(&corner, Direction::XBackward),
+        Some(grid.index_from_coords(0, 0, 0))
+    );
+    assert_eq!(
+        grid.get_neighbour_clamped(&corner, Direction::YForward),
+        Some(grid.index_from_coords(0, 1, 0))
+    );
+    let edge = CartesianPosition::new_xy(2, 1);
+    assert_eq!(
+        grid.get_neighbour_clamped(&edge, Direction::XForward),
+        Some(grid.index_from_coords(2, 1, 0))
+    );
+}
+
+#[test]
+fn wraps_on_looping_axes() {
+    let grid = CartesianGrid::new_cartesian_2d(3, 3, true, false);
+    assert_eq!(
+        grid.get_neighbour_clamped(&CartesianPosition::new_xy(0, 2), Direction::XBackward),
+        Some(grid.index_from_coords(2, 2, 0))
+    );
+}
+
+#[test]
+fn direction_not_in_the_coordinate_system() {
+    let grid = CartesianGrid::new_cartesian_2d(3, 3, false, false);
+    let center = CartesianPosition::new_xy(1, 1);
+    assert_eq!(
+        grid.get_neighbour_clamped(&center, Direction::ZForward),
+        None
+    );
+    assert_eq!(
+        grid.get_neighbour_clamped(&center, Direction::ZBackward),
+        None
+    );
+
+    let grid = CartesianGrid::new_cartesian_3d(3, 3, 3, false, false, false);
+    assert_eq!(
+        grid.get_neighbour_clamped(&CartesianPosition::new(1, 1, 2), Direction::ZForward),
+        Some(grid.index_from_coords(1, 1, 2))
+    );
+}
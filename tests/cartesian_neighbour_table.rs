@@ -0,0 +1,37 @@
+use ghx_grid::{
+    cartesian::{coordinates::CartesianCoordinates, grid::CartesianGrid},
+    grid::Grid,
+};
+
+fn assert_table_matches_neighbours<C: CartesianCoordinates>(grid: &CartesianGrid<C>) {
+    let table = grid.build_neighbour_offset_table();
+    assert_eq!(table.len(), grid.total_directional_size());
+    for index in grid.indexes() {
+        let neighbours = grid.collect_neighbours(index);
+        let start = grid.directional_index(index, 0);
+        assert_eq!(
+            &table[start..start + grid.directions_count()],
+            &neighbours[..],
+            "index {}",
+            index
+        );
+    }
+}
+
+#[test]
+fn neighbour_table_matches_neighbours_2d() {
+    for (looping_x, looping_y) in [(false, false), (true, false), (true, true)] {
+        assert_table_matches_neighbours(&CartesianGrid::new_cartesian_2d(
+            5, 3, looping_x, looping_y,
+        ));
+    }
+}
+
+#[test]
+fn neighbour_table_matches_neighbours_3d() {
+    for looping in [false, true] {
+        assert_table_matches_neighbours(&CartesianGrid::new_cartesian_3d(
+            4, 3, 2, looping, !looping, looping,
+        ));
+    }
+}
@@ -0,0 +1,64 @@
+use ghx_grid::{
+    cartesian::{coordinates::Neighbourhood, grid::CartesianGrid},
+    grid::GridIndex,
+};
+
+fn sorted(iter: impl Iterator<Item = GridIndex>) -> Vec<GridIndex> {
+    let mut neighbours: Vec<_> = iter.collect();
+    neighbours.sort();
+    neighbours
+}
+
+#[test]
+fn neighbours_in_non_looping() {
+    let grid = CartesianGrid::new_cartesian_2d(3, 3, false, false);
+    let center = grid.index_from_coords(1, 1, 0);
+    assert_eq!(
+        sorted(grid.neighbours_in(center, Neighbourhood::VonNeumann)),
+        vec![1, 3, 5, 7]
+    );
+    assert_eq!(
+        sorted(grid.neighbours_in(center, Neighbourhood::Moore)),
+        vec![0, 1, 2, 3, 5, 6, 7, 8]
+    );
+    assert_eq!(
+        sorted(grid.neighbours_in(0, Neighbourhood::Moore)),
+        vec![1, 3, 4]
+    );
+}
+
+#[test]
+fn neighbours_in_looping_size_1() {
+    let grid = CartesianGrid::new_cartesian_2d(1, 1, true, true);
+    assert_eq!(grid.neighbours_in(0, Neighbourhood::Moore).count(), 0);
+    assert_eq!(grid.neighbours_in(0, Neighbourhood::VonNeumann).count(), 0);
+    assert_eq!(grid.diagonal_neighbours(0).count(), 0);
+
+    // Only the looping axis of size 1 collapses
+    let grid = CartesianGrid::new_cartesian_2d(1, 3, true, false);
+    assert_eq!(
+        sorted(grid.neighbours_in(1, Neighbourhood::Moore)),
+        vec![0, 2]
+    );
+}
+
+#[test]
+fn neighbours_in_looping_size_2() {
+    let grid = CartesianGrid::new_cartesian_2d(2, 2, true, true);
+    // Left and right lead to the same element, as do up and down
+    assert_eq!(
+        sorted(grid.neighbours_in(0, Neighbourhood::VonNeumann)),
+        vec![1, 2]
+    );
+    assert_eq!(
+        sorted(grid.neighbours_in(0, Neighbourhood::Moore)),
+        vec![1, 2, 3]
+    );
+    assert_eq!(sorted(grid.diagonal_neighbours(0)), vec![3]);
+
+    let grid = CartesianGrid::new_cartesian_3d(2, 2, 2, true, true, true);
+    assert_eq!(
+        sorted(grid.neighbours_in(0, Neighbourhood::Moore)),
+        (1..8).collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,74 @@
+use ghx_grid::{
+    cartesian::{
+        coordinates::{CartesianPosition, GridDelta},
+        grid::CartesianGrid,
+    },
+    direction::Direction,
+};
+
+#[test]
+fn deltas_larger_than_looping_axis_wrap() {
+    let grid = CartesianGrid::new_cartesian_3d(4, 3, 5, true, true, true);
+    let origin = CartesianPosition::new(1, 1, 1);
+    assert_eq!(
+        grid.get_next_pos(&origin, &GridDelta::new(4 + 1, 0, 0)),
+        Some(CartesianPosition::new(2, 1, 1))
+    );
+    assert_eq!(
+        grid.get_next_pos(&origin, &GridDelta::new(0, 3 * 7 + 2, 0)),
+        Some(CartesianPosition::new(1, 0, 1))
+    );
+    assert_eq!(
+        grid.get_next_pos(&origin, &GridDelta::new(0, 0, 5 * 3)),
+        Some(origin)
+    );
+}
+
+#[test]
+fn negative_deltas_larger_than_looping_axis_wrap() {
+    let grid = CartesianGrid::new_cartesian_3d(4, 3, 5, true, true, true);
+    let origin = CartesianPosition::new(1, 1, 1);
+    assert_eq!(
+        grid.get_next_pos(&origin, &GridDelta::new(-(4 + 2), 0, 0)),
+        Some(CartesianPosition::new(3, 1, 1))
+    );
+    assert_eq!(
+        grid.get_next_pos(&origin, &GridDelta::new(0, -3 * 5, 0)),
+        Some(origin)
+    );
+    assert_eq!(
+        grid.get_next_pos(&origin, &GridDelta::new(-9, -7, -11)),
+        Some(CartesianPosition::new(0, 0, 0))
+    );
+}
+
+#[test]
+fn large_deltas_report_wrapping() {
+    let grid = CartesianGrid::new_cartesian_2d(4, 4, true, false);
+    let origin = CartesianPosition::new(0, 0, 0);
+    assert_eq!(
+        grid.get_next_pos_wrapped(&origin, &GridDelta::new(8, 0, 0)),
+        Some((origin, true))
+    );
+    assert_eq!(
+        grid.get_next_pos_wrapped(&origin, &GridDelta::new(3, 3, 0)),
+        Some((CartesianPosition::new(3, 3, 0), false))
+    );
+    // Non-looping axes still reject out of bounds destinations
+    assert_eq!(grid.get_next_pos(&origin, &GridDelta::new(0, 4, 0)), None);
+    assert_eq!(grid.get_next_pos(&origin, &GridDelta::new(9, -1, 0)), None);
+}
+
+#[test]
+fn index_in_direction_with_many_units() {
+    let grid = CartesianGrid::new_cartesian_2d(5, 3, true, true);
+    let origin = CartesianPosition::new(2, 1, 0);
+    assert_eq!(
+        grid.get_index_in_direction(&origin, Direction::XForward, 11),
+        Some(grid.index_from_coords(3, 1, 0))
+    );
+    assert_eq!(
+        grid.get_index_in_direction(&origin, Direction::YBackward, 8),
+        Some(grid.index_from_coords(2, 2, 0))
+    );
+}
@@ -0,0 +1,35 @@
+use ghx_grid::cartesian::coordinates::{CartesianPosition, GridDelta};
+
+#[test]
+fn position_sub_gives_delta() {
+    let a = CartesianPosition::new(5, 1, 3);
+    let b = CartesianPosition::new(2, 4, 3);
+    assert_eq!(a - b, GridDelta::new(3, -3, 0));
+    assert_eq!(b + (a - b), a);
+}
+
+#[test]
+fn checked_sub_out_of_i32_range() {
+    let far = CartesianPosition::new(u32::MAX, 0, 0);
+    let origin = CartesianPosition::new(0, 0, 0);
+    assert_eq!(far.checked_sub(&origin), None);
+    assert_eq!(origin.checked_sub(&far), None);
+    let edge = CartesianPosition::new(i32::MAX as u32, 0, 0);
+    assert_eq!(
+        edge.checked_sub(&origin),
+        Some(GridDelta::new(i32::MAX, 0, 0))
+    );
+}
+
+#[test]
+#[should_panic]
+fn sub_out_of_i32_range_panics() {
+    let _ = CartesianPosition::new(u32::MAX, 0, 0) - CartesianPosition::new(0, 0, 0);
+}
+
+#[test]
+fn manhattan_len_does_not_overflow() {
+    assert_eq!(GridDelta::new(3, -4, 1).manhattan_len(), 8);
+    let delta = GridDelta::new(i32::MIN, i32::MIN, i32::MAX);
+    assert_eq!(delta.manhattan_len(), 3 * (1 << 31) - 1);
+}
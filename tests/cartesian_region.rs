@@ -0,0 +1,56 @@
+use ghx_grid::cartesian::{
+    coordinates::CartesianPosition, grid::CartesianGrid, region::GridRegion,
+};
+
+#[test]
+fn subgrid_region_clamped_to_the_grid() {
+    let grid = CartesianGrid::new_cartesian_2d(4, 3, false, false);
+    let data = grid.grid_data_from_fn(|pos| pos.x + 10 * pos.y);
+    let region = GridRegion::new(
+        CartesianPosition::new_xy(1, 1),
+        CartesianPosition::new(9, 9, 1),
+    );
+    let subgrid = data.subgrid_region(&region).unwrap();
+    assert_eq!(subgrid.grid().size(), (3, 2, 1));
+    assert_eq!(subgrid.as_slice(), &[11, 12, 13, 21, 22, 23]);
+}
+
+#[test]
+fn subgrid_region_empty_is_none() {
+    let grid = CartesianGrid::new_cartesian_2d(4, 3, false, false);
+    let data = grid.new_grid_data(0u8);
+    // `min` greater than `max`
+    let region = GridRegion::new(
+        CartesianPosition::new(3, 2, 0),
+        CartesianPosition::new(1, 1, 1),
+    );
+    assert!(region.is_empty());
+    assert!(data.subgrid_region(&region).is_none());
+    // `min` equal to `max`
+    let region = GridRegion::new(
+        CartesianPosition::new(1, 1, 0),
+        CartesianPosition::new(1, 1, 0),
+    );
+    assert!(data.subgrid_region(&region).is_none());
+}
+
+#[test]
+fn subgrid_region_out_of_bounds_is_none() {
+    let grid = CartesianGrid::new_cartesian_2d(4, 3, false, false);
+    let data = grid.new_grid_data(0u8);
+    let region = GridRegion::from_size(CartesianPosition::new_xy(4, 0), (2, 2, 1));
+    assert!(!region.is_empty());
+    assert!(data.subgrid_region(&region).is_none());
+    let region = GridRegion::from_size(CartesianPosition::new(0, 0, 1), (2, 2, 1));
+    assert!(data.subgrid_region(&region).is_none());
+}
+
+#[test]
+fn region_volume_does_not_overflow() {
+    let region = GridRegion::new(
+        CartesianPosition::new(0, 0, 0),
+        CartesianPosition::new(u32::MAX, u32::MAX, 1),
+    );
+    assert_eq!(region.volume(), u64::from(u32::MAX) * u64::from(u32::MAX));
+    assert!(!region.is_empty());
+}
@@ -0,0 +1,57 @@
+use ghx_grid::{cartesian::grid::CartesianGrid, error::GridError};
+
+#[test]
+fn resize_keeps_overlapping_elements() {
+    let grid = CartesianGrid::new_cartesian_2d(3, 2, false, false);
+    let mut data = grid.grid_data_from_fn(|pos| pos.x + 10 * pos.y);
+    data.resize((2, 3, 1), 99).unwrap();
+    assert_eq!(data.grid().size(), (2, 3, 1));
+    assert_eq!(data.grid().size_xy(), 6);
+    assert_eq!(data.as_slice(), &[0, 1, 10, 11, 99, 99]);
+}
+
+#[test]
+fn resize_to_zero_dimension_fails() {
+    let grid = CartesianGrid::new_cartesian_2d(3, 2, false, false);
+    let mut data = grid.grid_data_from_fn(|pos| pos.x + 10 * pos.y);
+    let before = data.clone();
+    assert_eq!(
+        data.resize((0, 3, 1), 0),
+        Err(GridError::ZeroDimension { size: (0, 3, 1) })
+    );
+    assert_eq!(data, before);
+}
+
+#[test]
+fn resize_overflowing_size_fails() {
+    let grid = CartesianGrid::new_cartesian_2d(3, 2, false, false);
+    let mut data = grid.new_grid_data(0u8);
+    let before = data.clone();
+    assert_eq!(
+        data.resize((70000, 70000, 1), 0),
+        Err(GridError::DimensionOverflow {
+            size: (70000, 70000, 1)
+        })
+    );
+    assert_eq!(data, before);
+    assert!(data.resize((u32::MAX, 2, 1), 0).is_err());
+    assert_eq!(data, before);
+}
+
+#[test]
+fn resize_reuses_the_buffer() {
+    let grid = CartesianGrid::new_cartesian_3d(4, 4, 4, false, false, false);
+    let mut data = grid.grid_data_from_fn(|pos| pos.x + 10 * pos.y + 100 * pos.z);
+    let capacity = data.capacity();
+    data.resize((2, 3, 2), 0).unwrap();
+    assert_eq!(data.capacity(), capacity);
+    assert_eq!(data.as_slice().len(), 12);
+    assert_eq!(*data.get_3d(1, 2, 1), 121);
+
+    // A failed resize does not reallocate
+    assert!(data.resize((2, 0, 2), 0).is_err());
+    assert_eq!(data.capacity(), capacity);
+
+    data.shrink_to_fit();
+    assert!(data.capacity() < capacity);
+}
@@ -0,0 +1,45 @@
+use ghx_grid::cartesian::{coordinates::CartesianPosition, grid::CartesianGrid};
+
+#[test]
+fn subgrid_clamped_to_the_grid() {
+    let grid = CartesianGrid::new_cartesian_2d(4, 3, false, false);
+    let data = grid.grid_data_from_fn(|pos| pos.x + 10 * pos.y);
+    let subgrid = data
+        .subgrid(CartesianPosition::new_xy(2, 1), (5, 5))
+        .unwrap();
+    assert_eq!(subgrid.grid().size(), (2, 2, 1));
+    assert_eq!(subgrid.as_slice(), &[12, 13, 22, 23]);
+}
+
+#[test]
+fn subgrid_with_zero_size_is_none() {
+    let grid = CartesianGrid::new_cartesian_2d(4, 3, false, false);
+    let data = grid.new_grid_data(0u8);
+    assert!(data
+        .subgrid(CartesianPosition::new_xy(0, 0), (0, 2))
+        .is_none());
+    assert!(data
+        .subgrid(CartesianPosition::new_xy(1, 1), (2, 0))
+        .is_none());
+}
+
+#[test]
+fn subgrid_past_the_edge_is_none() {
+    let grid = CartesianGrid::new_cartesian_2d(4, 3, false, false);
+    let data = grid.new_grid_data(0u8);
+    assert!(data
+        .subgrid(CartesianPosition::new_xy(4, 0), (2, 2))
+        .is_none());
+    assert!(data
+        .subgrid(CartesianPosition::new_xy(0, 3), (2, 2))
+        .is_none());
+
+    let grid = CartesianGrid::new_cartesian_3d(2, 2, 2, false, false, false);
+    let data = grid.new_grid_data(0u8);
+    assert!(data
+        .subgrid(CartesianPosition::new(0, 0, 2), (1, 1, 1))
+        .is_none());
+    assert!(data
+        .subgrid(CartesianPosition::new(1, 1, 1), (0, 1, 1))
+        .is_none());
+}
@@ -0,0 +1,40 @@
+use ghx_grid::{
+    cartesian::{coordinates::Cartesian3D, grid::CartesianGrid},
+    grid::Grid,
+};
+
+#[test]
+fn volume_does_not_overflow_u32() {
+    let grid = CartesianGrid::new_cartesian_3d(2000, 2000, 1, false, false, false);
+    assert_eq!(grid.volume(), 4_000_000);
+    assert_eq!(grid.total_size(), 4_000_000);
+}
+
+#[cfg(not(feature = "large-grids"))]
+#[test]
+fn total_size_larger_than_u32_is_rejected() {
+    use ghx_grid::error::GridError;
+
+    let grid = CartesianGrid::try_new(2000, 2000, 2000, false, false, false, Cartesian3D);
+    assert_eq!(
+        grid,
+        Err(GridError::DimensionOverflow {
+            size: (2000, 2000, 2000)
+        })
+    );
+}
+
+#[cfg(feature = "large-grids")]
+#[test]
+fn total_size_larger_than_u32() {
+    use ghx_grid::cartesian::coordinates::CartesianPosition;
+
+    let grid = CartesianGrid::try_new(2000, 2000, 2000, false, false, false, Cartesian3D).unwrap();
+    assert_eq!(grid.volume(), 8_000_000_000);
+    assert_eq!(grid.total_size(), 8_000_000_000);
+
+    let last = CartesianPosition::new(1999, 1999, 1999);
+    let last_index = grid.index_from_pos(&last);
+    assert_eq!(last_index, 7_999_999_999);
+    assert_eq!(grid.pos_from_index(last_index), last);
+}
@@ -0,0 +1,63 @@
+use ghx_grid::cartesian::{
+    coordinates::{Cartesian3D, GridDelta},
+    grid::CartesianGrid,
+};
+
+fn deltas() -> Vec<GridDelta> {
+    let mut deltas = Vec::new();
+    for dx in -2..=2 {
+        for dy in -2..=2 {
+            for dz in -2..=2 {
+                deltas.push(GridDelta::new(dx, dy, dz));
+            }
+        }
+    }
+    deltas
+}
+
+fn assert_matches_next_pos(grid: &CartesianGrid<Cartesian3D>) {
+    for index in grid.indexes() {
+        let pos = grid.pos_from_index(index);
+        for delta in deltas() {
+            let expected = grid
+                .get_next_pos(&pos, &delta)
+                .map(|next| grid.index_from_pos(&next));
+            assert_eq!(
+                grid.wrap_index(index, &delta),
+                expected,
+                "index {index} ({pos}), delta {delta:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn wrap_index_matches_get_next_pos_for_all_looping_flags() {
+    for flags in 0..8 {
+        let grid = CartesianGrid::new_cartesian_3d(
+            3,
+            4,
+            5,
+            flags & 1 != 0,
+            flags & 2 != 0,
+            flags & 4 != 0,
+        );
+        assert_matches_next_pos(&grid);
+    }
+}
+
+#[test]
+fn wrap_index_on_row_edges() {
+    let grid = CartesianGrid::new_cartesian_3d(4, 3, 1, false, false, false);
+    // Moving right from the end of a row must not jump to the start of the next row.
+    assert_eq!(grid.wrap_index(3, &GridDelta::new(1, 0, 0)), None);
+    assert_eq!(grid.wrap_index(4, &GridDelta::new(-1, 0, 0)), None);
+    assert_eq!(grid.wrap_index(2, &GridDelta::new(1, 0, 0)), Some(3));
+
+    let looping = CartesianGrid::new_cartesian_3d(4, 3, 1, true, true, false);
+    assert_eq!(looping.wrap_index(3, &GridDelta::new(1, 0, 0)), Some(0));
+    assert_eq!(looping.wrap_index(4, &GridDelta::new(-1, 0, 0)), Some(7));
+    assert_eq!(looping.wrap_index(1, &GridDelta::new(0, -1, 0)), Some(9));
+    assert_eq!(looping.wrap_index(11, &GridDelta::new(1, 1, 0)), Some(0));
+    assert_eq!(looping.wrap_index(0, &GridDelta::new(0, 0, 1)), None);
+}
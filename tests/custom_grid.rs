@@ -0,0 +1,125 @@
+use ghx_grid::{
+    cartesian::{
+        coordinates::{CartesianPosition, GridDelta},
+        custom::{CustomCoordinates, CustomGrid},
+        grid::CartesianGrid,
+    },
+    coordinate_system::CoordinateSystem,
+    direction::{Axis, DirectionTrait},
+    grid::{Grid, GridIndex},
+};
+
+/// Knight moves in the XY plane
+fn knight_coordinates() -> CustomCoordinates {
+    CustomCoordinates::new(vec![
+        GridDelta::new(1, 2, 0),
+        GridDelta::new(2, 1, 0),
+        GridDelta::new(2, -1, 0),
+        GridDelta::new(1, -2, 0),
+        GridDelta::new(-1, -2, 0),
+        GridDelta::new(-2, -1, 0),
+        GridDelta::new(-2, 1, 0),
+        GridDelta::new(-1, 2, 0),
+    ])
+}
+
+#[test]
+fn custom_coordinates_construction() {
+    let coord_system = knight_coordinates();
+    assert_eq!(coord_system.directions_count(), 8);
+    assert_eq!(coord_system.direction_deltas(), Some(coord_system.deltas()));
+    for (index, direction) in coord_system.directions().iter().enumerate() {
+        assert_eq!(usize::from(*direction), index);
+        assert_eq!(
+            *coord_system.delta(direction.opposite()),
+            *coord_system.delta(*direction) * -1
+        );
+    }
+    let directions = coord_system.directions();
+    assert_eq!(directions[0].axis(), Axis::Y);
+    assert_eq!(directions[1].axis(), Axis::X);
+}
+
+#[test]
+fn direction_without_opposite_is_its_own_opposite() {
+    let coord_system = CustomCoordinates::new(vec![GridDelta::new(0, 0, 3)]);
+    let direction = coord_system.directions()[0];
+    assert_eq!(direction.opposite(), direction);
+    assert_eq!(direction.axis(), Axis::Z);
+}
+
+#[test]
+fn custom_grid_neighbours() {
+    let grid = CustomGrid::new(
+        CartesianGrid::new_cartesian_3d(5, 5, 1, false, false, false),
+        knight_coordinates(),
+    );
+    assert_eq!(grid.total_size(), 25);
+    assert_eq!(grid.directions_count(), 8);
+
+    let center = grid.index_from_pos(&CartesianPosition::new(2, 2, 0));
+    let mut neighbours = grid.neighbour_buffer();
+    grid.get_neighbours_in_all_directions(center, &mut neighbours);
+    assert!(neighbours.iter().all(|n| n.is_some()));
+    assert_eq!(
+        neighbours[0].map(|index| grid.pos_from_index(index)),
+        Some(CartesianPosition::new(3, 4, 0))
+    );
+
+    // Only two knight moves stay in the grid from a corner
+    let corner = grid.index_from_pos(&CartesianPosition::new(0, 0, 0));
+    let mut in_grid: Vec<GridIndex> = grid
+        .collect_neighbours(corner)
+        .into_iter()
+        .flatten()
+        .collect();
+    in_grid.sort();
+    assert_eq!(
+        in_grid,
+        vec![
+            grid.index_from_pos(&CartesianPosition::new(2, 1, 0)),
+            grid.index_from_pos(&CartesianPosition::new(1, 2, 0)),
+        ]
+    );
+    for direction in grid.directions() {
+        assert_eq!(
+            grid.neighbour(corner, *direction),
+            grid.collect_neighbours(corner)[usize::from(*direction)]
+        );
+    }
+}
+
+#[test]
+fn custom_grid_neighbours_when_looping() {
+    let grid = CustomGrid::new(
+        CartesianGrid::new_cartesian_3d(5, 5, 1, true, true, false),
+        knight_coordinates(),
+    );
+    let corner = grid.index_from_pos(&CartesianPosition::new(0, 0, 0));
+    let direction = grid.directions()[4];
+    assert_eq!(
+        grid.neighbour(corner, direction)
+            .map(|index| grid.pos_from_index(index)),
+        Some(CartesianPosition::new(4, 3, 0))
+    );
+    assert_eq!(grid.in_bounds_neighbour_count(corner), 8);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_validates_directions() {
+    let coord_system = knight_coordinates();
+    let json = serde_json::to_string(&coord_system).unwrap();
+    let deserialized: CustomCoordinates = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, coord_system);
+
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    // One direction less than deltas
+    value["directions"].as_array_mut().unwrap().pop();
+    assert!(serde_json::from_value::<CustomCoordinates>(value.clone()).is_err());
+
+    // Directions which do not match their deltas
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value["directions"].as_array_mut().unwrap().swap(0, 1);
+    assert!(serde_json::from_value::<CustomCoordinates>(value).is_err());
+}
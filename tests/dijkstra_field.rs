@@ -0,0 +1,21 @@
+use ghx_grid::cartesian::grid::CartesianGrid;
+
+#[test]
+fn costs_from_the_nearest_source() {
+    let grid = CartesianGrid::new_cartesian_2d(5, 1, false, false);
+    // The element at x = 2 is impassable
+    let data = grid.grid_data_from_fn(|pos| pos.x != 2);
+    let field = data.dijkstra_field(&[0, 4], |passable| passable.then_some(1));
+    assert_eq!(
+        field.as_slice(),
+        &[Some(0), Some(1), None, Some(1), Some(0)]
+    );
+}
+
+#[test]
+#[should_panic(expected = "not a valid index")]
+fn out_of_range_source_panics() {
+    let grid = CartesianGrid::new_cartesian_2d(3, 3, false, false);
+    grid.new_grid_data(1u32)
+        .dijkstra_field(&[9], |cost| Some(*cost));
+}
@@ -0,0 +1,73 @@
+use ghx_grid::{
+    cartesian::{coordinates::CartesianPosition, grid::CartesianGrid},
+    error::GridError,
+};
+
+#[test]
+fn zip_with_same_grid() {
+    let grid = CartesianGrid::new_cartesian_2d(2, 3, false, false);
+    let a = grid.grid_data_from_fn(|pos| pos.x);
+    let b = grid.grid_data_from_fn(|pos| pos.y);
+    let sum = a.zip_with(&b, |a, b| a + 10 * b).unwrap();
+    assert_eq!(sum.as_slice(), &[0, 1, 10, 11, 20, 21]);
+}
+
+#[test]
+fn zip_with_different_total_size() {
+    let a = CartesianGrid::new_cartesian_2d(2, 3, false, false).new_grid_data(0);
+    let b = CartesianGrid::new_cartesian_2d(2, 2, false, false).new_grid_data(0);
+    assert_eq!(
+        a.zip_with(&b, |a, b| a + b),
+        Err(GridError::DimensionMismatch {
+            expected: 6,
+            got: 4
+        })
+    );
+}
+
+#[test]
+fn zip_with_same_total_size_different_shape() {
+    let a = CartesianGrid::new_cartesian_2d(2, 3, false, false).new_grid_data(0);
+    let b = CartesianGrid::new_cartesian_2d(3, 2, false, false).new_grid_data(0);
+    assert_eq!(a.zip_with(&b, |a, b| a + b), Err(GridError::GridMismatch));
+}
+
+#[test]
+fn apply_mask_same_grid() {
+    let grid = CartesianGrid::new_cartesian_2d(2, 3, false, false);
+    let mut data = grid.grid_data_from_fn(|pos| pos.x + 10 * pos.y);
+    let mask = grid.grid_data_from_fn(|pos| pos.x == 1);
+    data.apply_mask(&mask, 0).unwrap();
+    assert_eq!(data.as_slice(), &[0, 1, 0, 11, 0, 21]);
+}
+
+#[test]
+fn apply_mask_same_total_size_different_shape() {
+    let mut data = CartesianGrid::new_cartesian_2d(2, 3, false, false).new_grid_data(1);
+    let mask = CartesianGrid::new_cartesian_2d(3, 2, false, false).new_grid_data(false);
+    assert_eq!(data.apply_mask(&mask, 0), Err(GridError::GridMismatch));
+    // The data is left unchanged
+    assert!(data.as_slice().iter().all(|d| *d == 1));
+    assert_eq!(
+        data.where_mask(&mask, |d| *d = 0),
+        Err(GridError::GridMismatch)
+    );
+}
+
+#[test]
+fn diff_same_grid() {
+    let grid = CartesianGrid::new_cartesian_2d(2, 3, false, false);
+    let a = grid.new_grid_data(0);
+    let mut b = a.clone();
+    *b.get_mut(3) = 1;
+    assert_eq!(a.diff_indices(&b), Ok(vec![3]));
+    assert_eq!(a.diff(&b), Ok(vec![(CartesianPosition::new(1, 1, 0), &0)]));
+}
+
+#[test]
+fn diff_same_total_size_different_shape() {
+    let a = CartesianGrid::new_cartesian_2d(2, 3, false, false).new_grid_data(0);
+    let b = CartesianGrid::new_cartesian_2d(3, 2, false, false).new_grid_data(0);
+    assert_eq!(a.diff_indices(&b), Err(GridError::GridMismatch));
+    assert_eq!(a.diff(&b), Err(GridError::GridMismatch));
+}
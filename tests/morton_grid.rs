@@ -0,0 +1,92 @@
+// `GridIndex` is already a `usize` with `large-grids`, making some casts redundant
+#![cfg_attr(feature = "large-grids", allow(clippy::unnecessary_cast))]
+
+use ghx_grid::{
+    cartesian::{
+        coordinates::{Cartesian2D, Cartesian3D, CartesianPosition},
+        grid::CartesianGrid,
+        morton::MortonGrid,
+    },
+    direction::Direction,
+    grid::Grid,
+};
+
+fn assert_round_trip(size_x: u32, size_y: u32, size_z: u32) {
+    let grid = MortonGrid::new(size_x, size_y, size_z, false, false, false, Cartesian3D);
+    let mut seen = vec![false; grid.total_size()];
+    for pos in CartesianGrid::new_cartesian_3d(size_x, size_y, size_z, false, false, false)
+        .iter_positions()
+    {
+        let index = grid.index_from_pos(&pos);
+        assert!((index as usize) < grid.total_size(), "{:?}", pos);
+        assert!(!seen[index as usize], "index {} used twice", index);
+        seen[index as usize] = true;
+        assert_eq!(grid.pos_from_index(index), pos);
+    }
+    for index in 0..grid.total_size() {
+        let pos = grid.pos_from_index(index as _);
+        assert_eq!(grid.index_from_pos(&pos) as usize, index);
+    }
+}
+
+#[test]
+fn morton_round_trip_cube() {
+    assert_round_trip(8, 8, 8);
+}
+
+#[test]
+fn morton_round_trip_different_sizes() {
+    assert_round_trip(16, 4, 2);
+    assert_round_trip(1, 8, 2);
+    assert_round_trip(32, 1, 1);
+}
+
+#[test]
+fn morton_interleaves_bits() {
+    let grid = MortonGrid::new(4, 4, 4, false, false, false, Cartesian3D);
+    assert_eq!(grid.index_from_pos(&CartesianPosition::new(1, 0, 0)), 1);
+    assert_eq!(grid.index_from_pos(&CartesianPosition::new(0, 1, 0)), 2);
+    assert_eq!(grid.index_from_pos(&CartesianPosition::new(0, 0, 1)), 4);
+    assert_eq!(grid.index_from_pos(&CartesianPosition::new(1, 1, 1)), 7);
+    assert_eq!(grid.index_from_pos(&CartesianPosition::new(2, 0, 0)), 8);
+}
+
+#[test]
+fn morton_neighbours_match_cartesian() {
+    let morton = MortonGrid::new(4, 8, 2, true, false, true, Cartesian3D);
+    let cartesian = morton.cartesian_grid();
+    for pos in cartesian.iter_positions() {
+        for direction in ghx_grid::direction::Direction::all() {
+            assert_eq!(
+                morton
+                    .neighbour(morton.index_from_pos(&pos), direction)
+                    .map(|index| morton.pos_from_index(index)),
+                cartesian.get_next_pos_in_direction(&pos, direction)
+            );
+        }
+    }
+}
+
+#[test]
+fn morton_2d_has_no_z_neighbours() {
+    let grid = MortonGrid::new(4, 4, 1, false, false, false, Cartesian2D);
+    let index = grid.index_from_coords(1, 1, 0);
+    assert_eq!(grid.neighbour(index, Direction::ZForward), None);
+    assert_eq!(grid.neighbour(index, Direction::ZBackward), None);
+    assert_eq!(
+        grid.neighbour(index, Direction::XForward),
+        Some(grid.index_from_coords(2, 1, 0))
+    );
+}
+
+#[test]
+#[should_panic(expected = "powers of two")]
+fn morton_rejects_non_power_of_two_size() {
+    MortonGrid::new(6, 8, 8, false, false, false, Cartesian3D);
+}
+
+#[test]
+#[should_panic(expected = "powers of two")]
+fn morton_rejects_zero_size() {
+    MortonGrid::new(8, 0, 8, false, false, false, Cartesian3D);
+}
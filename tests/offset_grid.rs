@@ -0,0 +1,39 @@
+use ghx_grid::cartesian::{
+    coordinates::CartesianPosition, grid::CartesianGrid, offset::OffsetGrid,
+};
+
+#[test]
+fn world_and_local_positions() {
+    let grid = CartesianGrid::new_cartesian_3d(4, 3, 2, false, false, false)
+        .with_origin(CartesianPosition::new(10, 20, 30));
+    let local = CartesianPosition::new(3, 2, 1);
+    let world = grid.to_world(&local).unwrap();
+    assert_eq!(world, CartesianPosition::new(13, 22, 31));
+    assert_eq!(grid.to_local(&world), Some(local));
+    assert_eq!(grid.to_local(&CartesianPosition::new(9, 20, 30)), None);
+    assert_eq!(grid.pos_from_index(0), CartesianPosition::new(10, 20, 30));
+}
+
+#[test]
+fn to_world_overflow_is_none() {
+    let origin = CartesianPosition::new(u32::MAX - 3, 0, 0);
+    let grid = CartesianGrid::new_cartesian_3d(4, 1, 1, false, false, false).with_origin(origin);
+    assert_eq!(
+        grid.to_world(&CartesianPosition::new(3, 0, 0)),
+        Some(CartesianPosition::new(u32::MAX, 0, 0))
+    );
+    assert_eq!(grid.to_world(&CartesianPosition::new(4, 0, 0)), None);
+    assert_eq!(
+        grid.pos_from_index(3),
+        CartesianPosition::new(u32::MAX, 0, 0)
+    );
+}
+
+#[test]
+#[should_panic(expected = "extends past the largest world position")]
+fn grid_past_the_largest_world_position() {
+    OffsetGrid::new(
+        CartesianGrid::new_cartesian_3d(4, 1, 1, false, false, false),
+        CartesianPosition::new(u32::MAX - 2, 0, 0),
+    );
+}
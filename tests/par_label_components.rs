@@ -0,0 +1,66 @@
+#![cfg(feature = "rayon")]
+
+use ghx_grid::{
+    cartesian::{coordinates::Cartesian2D, grid::CartesianGrid},
+    grid::{Grid, GridData},
+};
+
+/// Deterministic pseudo-random values in `0..range`
+fn noise(seed: u64, len: usize, range: u64) -> Vec<u8> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 33) % range) as u8
+        })
+        .collect()
+}
+
+/// Checks that `par_label_components` returns the same labels as `label_components`, with several thread counts so that tiles boundaries fall in different places
+fn assert_same_labels(data: &GridData<Cartesian2D, u8, CartesianGrid<Cartesian2D>>) {
+    let expected = data.label_components(|a, b| a == b);
+    for threads in [1, 2, 3, 4, 7] {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        let labels = pool.install(|| data.par_label_components(|a, b| a == b));
+        assert_eq!(labels, expected, "with {} threads", threads);
+    }
+}
+
+#[test]
+fn same_labels_as_serial_on_noise() {
+    for (seed, range) in [(1, 2), (2, 3), (3, 5)] {
+        let grid = CartesianGrid::new_cartesian_2d(37, 23, false, false);
+        let data = GridData::new(grid.clone(), noise(seed, grid.total_size(), range));
+        assert_same_labels(&data);
+    }
+}
+
+#[test]
+fn same_labels_as_serial_when_looping() {
+    let grid = CartesianGrid::new_cartesian_2d(16, 16, true, true);
+    let data = GridData::new(grid.clone(), noise(4, grid.total_size(), 2));
+    assert_same_labels(&data);
+}
+
+#[test]
+fn components_spanning_tiles_boundaries() {
+    // A snake going back and forth on every other row spans all the tiles, and is separated from the walls between its rows
+    let grid = CartesianGrid::new_cartesian_2d(20, 31, false, false);
+    let data = grid.grid_data_from_fn(|pos| {
+        let on_snake =
+            pos.y % 2 == 0 || (pos.y % 4 == 1 && pos.x == 19) || (pos.y % 4 == 3 && pos.x == 0);
+        u8::from(on_snake)
+    });
+    let (labels, count) = data.label_components(|a, b| a == b);
+    // The snake and the 15 walls between its rows
+    assert_eq!(count, 16);
+    for y in (0..31).step_by(2) {
+        assert_eq!(labels.get(grid.index_from_coords(19, y, 0)), labels.get(0));
+    }
+    assert_same_labels(&data);
+}
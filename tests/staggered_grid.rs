@@ -0,0 +1,189 @@
+use ghx_grid::{
+    cartesian::coordinates::CartesianPosition2D,
+    coordinate_system::CoordinateSystem,
+    direction::{Axis, DirectionTrait},
+    error::GridError,
+    grid::{Grid, GridIndex},
+    staggered::{coordinates::StaggeredDirection, grid::StaggeredGrid},
+};
+
+fn neighbours(grid: &StaggeredGrid, x: u32, y: u32) -> Vec<Option<(u32, u32)>> {
+    let mut buffer = grid.neighbour_buffer();
+    grid.get_neighbours_in_all_directions(
+        grid.index_from_pos(&CartesianPosition2D::new(x, y)),
+        &mut buffer,
+    );
+    buffer
+        .iter()
+        .map(|n| n.map(|index| StaggeredGrid::pos_from_index(grid, index)))
+        .map(|p| p.map(|p| (p.x, p.y)))
+        .collect()
+}
+
+#[test]
+fn even_row_neighbours() {
+    let grid = StaggeredGrid::new(5, 5, false, false);
+    assert_eq!(
+        neighbours(&grid, 2, 2),
+        vec![
+            Some((3, 2)),
+            Some((2, 3)),
+            Some((1, 3)),
+            Some((1, 2)),
+            Some((1, 1)),
+            Some((2, 1)),
+        ]
+    );
+}
+
+#[test]
+fn odd_row_neighbours() {
+    let grid = StaggeredGrid::new(5, 5, false, false);
+    assert_eq!(
+        neighbours(&grid, 2, 1),
+        vec![
+            Some((3, 1)),
+            Some((3, 2)),
+            Some((2, 2)),
+            Some((1, 1)),
+            Some((2, 0)),
+            Some((3, 0)),
+        ]
+    );
+}
+
+#[test]
+fn edge_neighbours_depend_on_row_parity() {
+    let grid = StaggeredGrid::new(4, 4, false, false);
+    // Even row: the western diagonals are out of the grid on the first column
+    assert_eq!(
+        neighbours(&grid, 0, 2),
+        vec![Some((1, 2)), Some((0, 3)), None, None, None, Some((0, 1)),]
+    );
+    // Odd row: the eastern diagonals are out of the grid on the last column
+    assert_eq!(
+        neighbours(&grid, 3, 1),
+        vec![None, None, Some((3, 2)), Some((2, 1)), Some((3, 0)), None,]
+    );
+}
+
+#[test]
+fn looping_neighbours() {
+    let grid = StaggeredGrid::new(4, 4, true, true);
+    assert_eq!(
+        neighbours(&grid, 0, 0),
+        vec![
+            Some((1, 0)),
+            Some((0, 1)),
+            Some((3, 1)),
+            Some((3, 0)),
+            Some((3, 3)),
+            Some((0, 3)),
+        ]
+    );
+    assert_eq!(
+        neighbours(&grid, 3, 3),
+        vec![
+            Some((0, 3)),
+            Some((0, 0)),
+            Some((3, 0)),
+            Some((2, 3)),
+            Some((3, 2)),
+            Some((0, 2)),
+        ]
+    );
+}
+
+#[test]
+fn moving_back_and_forth_returns_to_start() {
+    let grid = StaggeredGrid::new(6, 6, false, false);
+    for y in 1..5 {
+        let pos = CartesianPosition2D::new(2, y);
+        for dir in grid.coord_system().directions() {
+            let next = grid.get_next_pos_in_direction(&pos, *dir).unwrap();
+            assert_eq!(
+                grid.get_next_pos_in_direction(&next, dir.opposite()),
+                Some(pos)
+            );
+        }
+    }
+}
+
+#[test]
+fn position_index_round_trip() {
+    let grid = StaggeredGrid::new(3, 4, false, false);
+    for index in 0..grid.total_size() as GridIndex {
+        let pos = StaggeredGrid::pos_from_index(&grid, index);
+        assert_eq!(StaggeredGrid::index_from_pos(&grid, &pos), index);
+    }
+    assert_eq!(
+        grid.get_next_pos_in_direction(&CartesianPosition2D::new(0, 0), StaggeredDirection::East),
+        Some(CartesianPosition2D::new(1, 0))
+    );
+}
+
+#[test]
+fn valid_positions_at_row_edges() {
+    let grid = StaggeredGrid::new(3, 3, false, false);
+    // Last element of even and odd rows
+    assert!(grid.is_valid_pos(&CartesianPosition2D::new(2, 0)));
+    assert!(grid.is_valid_pos(&CartesianPosition2D::new(2, 1)));
+    assert!(grid.is_valid_pos(&CartesianPosition2D::new(2, 2)));
+    // Past the end of a row, which would map to the start of the next row
+    assert!(!grid.is_valid_pos(&CartesianPosition2D::new(3, 0)));
+    assert!(!grid.is_valid_pos(&CartesianPosition2D::new(3, 1)));
+    assert!(!grid.is_valid_pos(&CartesianPosition2D::new(3, 2)));
+    // Past the last row
+    assert!(!grid.is_valid_pos(&CartesianPosition2D::new(0, 3)));
+}
+
+#[test]
+fn rejected_sizes() {
+    assert_eq!(
+        StaggeredGrid::try_new(0, 4, false, false),
+        Err(GridError::ZeroDimension { size: (0, 4, 1) })
+    );
+    assert_eq!(
+        StaggeredGrid::try_new(4, 0, false, false),
+        Err(GridError::ZeroDimension { size: (4, 0, 1) })
+    );
+    assert_eq!(
+        StaggeredGrid::try_new(70000, 70000, false, false),
+        Err(GridError::DimensionOverflow {
+            size: (70000, 70000, 1)
+        })
+    );
+    assert_eq!(
+        StaggeredGrid::try_new(4, 3, false, true),
+        Err(GridError::OddLoopingSize {
+            axis: Axis::Y,
+            size: 3
+        })
+    );
+    assert!(StaggeredGrid::try_new(4, 3, true, false).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "zero dimension")]
+fn new_panics_on_zero_size() {
+    StaggeredGrid::new(0, 4, false, false);
+}
+
+#[test]
+fn default_grid_has_one_element() {
+    let grid = StaggeredGrid::default();
+    assert_eq!(grid.total_size(), 1);
+    assert_eq!(grid.default_grid_data::<u8>().as_slice(), &[0]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_validates_sizes() {
+    let grid = StaggeredGrid::new(4, 2, false, true);
+    let json = serde_json::to_string(&grid).unwrap();
+    assert_eq!(serde_json::from_str::<StaggeredGrid>(&json).unwrap(), grid);
+    let zero_sized = json.replace("\"size_x\":4", "\"size_x\":0");
+    assert!(serde_json::from_str::<StaggeredGrid>(&zero_sized).is_err());
+    let odd_looping = json.replace("\"size_y\":2", "\"size_y\":3");
+    assert!(serde_json::from_str::<StaggeredGrid>(&odd_looping).is_err());
+}
@@ -0,0 +1,99 @@
+use ghx_grid::{
+    cartesian::{
+        axis_swapped::{AxisOrder, AxisSwappedGrid},
+        coordinates::{Cartesian3D, CartesianPosition, CartesianPosition2D, GridDelta},
+        custom::{CustomCoordinates, CustomGrid},
+        grid::CartesianGrid,
+        morton::MortonGrid,
+    },
+    grid::{precomputed::PrecomputedNeighbours, Grid},
+    staggered::grid::StaggeredGrid,
+};
+
+#[test]
+fn cartesian_2d() {
+    let grid = CartesianGrid::new_cartesian_2d(3, 3, false, false);
+    let try_index = |x, y| grid.try_index_from_pos(&CartesianPosition2D::new(x, y));
+    assert_eq!(try_index(2, 0), Some(2));
+    assert_eq!(try_index(0, 1), Some(3));
+    assert_eq!(try_index(3, 0), None);
+    assert_eq!(try_index(0, 3), None);
+}
+
+#[test]
+fn cartesian_3d() {
+    let grid = CartesianGrid::new_cartesian_3d(3, 3, 2, false, false, false);
+    let try_index = |x, y, z| grid.try_index_from_pos(&CartesianPosition::new(x, y, z));
+    assert_eq!(try_index(2, 2, 1), Some(17));
+    assert_eq!(try_index(3, 0, 0), None);
+    assert_eq!(try_index(0, 3, 0), None);
+    assert_eq!(try_index(0, 0, 2), None);
+}
+
+#[test]
+fn staggered() {
+    let grid = StaggeredGrid::new(3, 3, false, false);
+    let try_index = |x, y| grid.try_index_from_pos(&CartesianPosition2D::new(x, y));
+    assert_eq!(try_index(2, 0), Some(2));
+    assert_eq!(try_index(2, 1), Some(5));
+    // Past the end of even and odd rows
+    assert_eq!(try_index(3, 0), None);
+    assert_eq!(try_index(3, 1), None);
+    assert_eq!(try_index(0, 3), None);
+}
+
+#[test]
+fn morton() {
+    let grid = MortonGrid::new(4, 4, 2, false, false, false, Cartesian3D);
+    let try_index = |x, y, z| grid.try_index_from_pos(&CartesianPosition::new(x, y, z));
+    assert_eq!(try_index(3, 3, 1), Some(grid.index_from_coords(3, 3, 1)));
+    assert_eq!(try_index(4, 0, 0), None);
+    assert_eq!(try_index(0, 4, 0), None);
+    assert_eq!(try_index(0, 0, 2), None);
+}
+
+#[test]
+fn offset() {
+    let grid = CartesianGrid::new_cartesian_3d(3, 3, 1, false, false, false)
+        .with_origin(CartesianPosition::new(10, 10, 0));
+    let try_index = |x, y| grid.try_index_from_pos(&CartesianPosition::new(x, y, 0));
+    assert_eq!(try_index(10, 10), Some(0));
+    assert_eq!(try_index(12, 11), Some(5));
+    assert_eq!(try_index(13, 10), None);
+    assert_eq!(try_index(9, 10), None);
+    assert_eq!(try_index(2, 0), None);
+}
+
+#[test]
+fn axis_swapped() {
+    let grid = AxisSwappedGrid::new(
+        CartesianGrid::new_cartesian_3d(3, 2, 1, false, false, false),
+        AxisOrder::YXZ,
+    );
+    let try_index = |x, y| grid.try_index_from_pos(&CartesianPosition::new(x, y, 0));
+    assert_eq!(try_index(1, 0), Some(2));
+    assert_eq!(try_index(2, 1), Some(5));
+    assert_eq!(try_index(0, 2), None);
+    assert_eq!(try_index(3, 0), None);
+}
+
+#[test]
+fn custom() {
+    let grid = CustomGrid::new(
+        CartesianGrid::new_cartesian_3d(3, 3, 1, false, false, false),
+        CustomCoordinates::new(vec![GridDelta::new(1, 1, 0)]),
+    );
+    let try_index = |x, y| grid.try_index_from_pos(&CartesianPosition::new(x, y, 0));
+    assert_eq!(try_index(2, 0), Some(2));
+    assert_eq!(try_index(3, 0), None);
+    assert_eq!(try_index(0, 3), None);
+}
+
+#[test]
+fn precomputed() {
+    let grid = PrecomputedNeighbours::new(StaggeredGrid::new(3, 3, false, false));
+    let try_index = |x, y| grid.try_index_from_pos(&CartesianPosition2D::new(x, y));
+    assert_eq!(try_index(2, 1), Some(5));
+    assert_eq!(try_index(3, 0), None);
+    assert_eq!(try_index(0, 3), None);
+}